@@ -0,0 +1,119 @@
+//! Times rendering a grid each frame, and counts the allocations it makes.
+//!
+//! Run with `cargo bench --bench render`. Reusing one buffer with [`Grid::render_into`] should
+//! allocate only on the first frame, while [`Grid::to_string`] allocates a new string every
+//! frame. Drawing a mostly static board straight into the frame buffer with a [`Renderer`] is
+//! compared against the old path of laying out that string with a [`Paragraph`].
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use rand::{SeedableRng, rngs::StdRng};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Paragraph, Widget},
+};
+use ratgol::{
+    game::Game,
+    grid::Grid,
+    render::{BlockRenderer, Renderer, Theme},
+};
+
+/// Seed for every board's random soup.
+const SEED: u64 = 0x5eed;
+/// Generations a soup runs for to settle into a mostly static board of still lifes and blinkers.
+const SETTLE_GENERATIONS: u64 = 2000;
+/// Time spent timing frames for each board and method, after one untimed warm-up frame.
+const TARGET_TIME: Duration = Duration::from_secs(2);
+
+/// Counts every allocation and reallocation made through the system allocator.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Renders frames with `frame` for [`TARGET_TIME`] and prints the time and allocations per frame.
+fn bench(name: &str, grid: &Grid, mut frame: impl FnMut(&Grid)) {
+    frame(grid);
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let mut frames = 0u64;
+    while start.elapsed() < TARGET_TIME {
+        frame(black_box(grid));
+        frames += 1;
+    }
+    let per_frame = start.elapsed() / frames as u32;
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) as f64 / frames as f64;
+    println!(
+        "{name} {}x{}: {per_frame:?}/frame, {allocations:.2} allocations/frame over {frames} frames",
+        grid.width(),
+        grid.height()
+    );
+}
+
+fn main() {
+    for (width, height) in [(64, 64), (256, 256)] {
+        let mut game = Game::with_rng((width, height), StdRng::seed_from_u64(SEED));
+        game.randomize(0.3);
+        let grid = &game.grid;
+
+        let mut buf = String::new();
+        bench("render_into", grid, |grid| {
+            grid.render_into(&mut buf);
+            black_box(&buf);
+        });
+        bench("to_string", grid, |grid| {
+            black_box(grid.to_string());
+        });
+    }
+    // A settled board, drawn into a ratatui frame buffer that starts out blank every frame
+    for (width, height) in [(64, 64), (128, 128)] {
+        let mut game = Game::with_rng((width, height), StdRng::seed_from_u64(SEED));
+        game.randomize(0.3);
+        for _ in 0..SETTLE_GENERATIONS {
+            game.step();
+        }
+        let grid = &game.grid;
+        let renderer = BlockRenderer { cell_width: 2 };
+        let (chars_wide, chars_high) = renderer.display_size(width, height);
+        let area = Rect::new(0, 0, chars_wide as u16, chars_high as u16);
+        let theme = Theme::default();
+
+        let mut buf = Buffer::empty(area);
+        bench("Renderer::render", grid, |grid| {
+            buf.reset();
+            renderer.render(grid, &theme, area, &mut buf);
+            black_box(&buf);
+        });
+        let mut buf = Buffer::empty(area);
+        bench("Paragraph", grid, |grid| {
+            buf.reset();
+            Paragraph::new(grid.to_string()).render(area, &mut buf);
+            black_box(&buf);
+        });
+    }
+}
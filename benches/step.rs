@@ -0,0 +1,37 @@
+//! Times [`Game::step`] on random soups of a few sizes.
+//!
+//! Run with `cargo bench --bench step`. Each board is seeded, so runs compare the same work from
+//! change to change, and Criterion reports how the timings moved since the last run.
+
+use std::hint::black_box;
+
+use criterion::{
+    BenchmarkId, Criterion, SamplingMode, Throughput, criterion_group, criterion_main,
+};
+use rand::{SeedableRng, rngs::StdRng};
+use ratgol::game::Game;
+
+/// Seed for every board's random soup.
+const SEED: u64 = 0x5eed;
+
+fn step(c: &mut Criterion) {
+    let mut group = c.benchmark_group("step");
+    // A generation of the largest board takes long enough that the default sampling, which
+    // runs ever more iterations per sample, would take minutes
+    group.sampling_mode(SamplingMode::Flat).sample_size(20);
+    for (width, height) in [(64, 64), (256, 256), (1024, 1024)] {
+        let mut game = Game::with_rng((width, height), StdRng::seed_from_u64(SEED));
+        game.randomize(0.3);
+        group.throughput(Throughput::Elements((width * height) as u64));
+        group.bench_function(
+            BenchmarkId::from_parameter(format!("{width}x{height}")),
+            |b| {
+                b.iter(|| black_box(&mut game).step_n_bench(black_box(1)));
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, step);
+criterion_main!(benches);
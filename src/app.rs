@@ -1,27 +1,441 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use std::{
+    borrow::Cow,
+    cell::Cell,
+    path::PathBuf,
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::DefaultTerminal;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
 
+#[cfg(feature = "log")]
+use crate::metrics::MetricsLog;
+#[cfg(feature = "stream")]
+use crate::stream::StreamServer;
 use crate::{
+    autosave::{self, Autosave},
+    clipboard,
+    command::{Command, parse_fit_margin},
+    condition::StopCondition,
     event::{AppEvent, Event, EventHandler},
-    game::Game,
-    ui::calculate_grid_size,
+    game::{Game, GameSnapshot},
+    grid::{BrushShape, CellState, DEFAULT_MAX_CELLS, Grid, GridBackend},
+    history::CsvLog,
+    keymap::{self, Action},
+    patterns::{self, Pattern},
+    replay::{MAX_RECORDING_LENGTH, Recording},
+    rule::Rule,
+    scene::Scene,
+    ui::{
+        DEFAULT_CELL_WIDTH, MAX_CELL_WIDTH, MAX_GRID_HEIGHT, MAX_GRID_WIDTH, RenderMode,
+        calculate_grid_size,
+    },
 };
 
+/// Number of quick-save slots.
+const SAVE_SLOTS: usize = 9;
+
+/// Time spent stepping per frame in turbo mode, giving roughly 60 frames per second.
+const TURBO_FRAME: Duration = Duration::from_millis(16);
+
+/// Time between progress reports from a background jump.
+const JUMP_PROGRESS_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Largest selectable drawing brush radius in cells.
+const MAX_BRUSH_RADIUS: usize = 10;
+
+/// Returns the key hints shown in the status bar, most important first.
+pub fn key_hints() -> &'static [KeyHint] {
+    keymap::hints()
+}
+
+/// Returns every key and mouse binding, listed in the help overlay.
+pub fn key_bindings() -> &'static [KeyHint] {
+    keymap::help()
+}
+
+/// Status message shown in move mode.
+const MOVE_HINT: &str = "move: shift the board with the arrows, Enter or Esc when done";
+
+/// Terminal size assumed when it can't be queried (e.g. no controlling terminal).
+const FALLBACK_TERMINAL_SIZE: (u16, u16) = (80, 24);
+
+/// A key binding and a short description of what it does, for display as help.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyHint {
+    /// Key or keys that trigger the action.
+    pub key: Cow<'static, str>,
+    /// Short description of the action.
+    pub action: &'static str,
+}
+
+impl KeyHint {
+    /// Creates a key hint.
+    pub const fn new(key: &'static str, action: &'static str) -> Self {
+        Self {
+            key: Cow::Borrowed(key),
+            action,
+        }
+    }
+
+    /// Creates a key hint for keys whose label is built at runtime.
+    pub fn labelled(key: String, action: &'static str) -> Self {
+        Self {
+            key: Cow::Owned(key),
+            action,
+        }
+    }
+}
+
+/// What fitting the grid to a pattern larger than the maximum grid size does.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OversizePolicy {
+    /// Cap the grid at the maximum size, clipping the pattern.
+    #[default]
+    Clip,
+    /// Size the grid to the whole pattern anyway, even if it can't be displayed.
+    Allow,
+}
+
+impl FromStr for OversizePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "clip" => Ok(OversizePolicy::Clip),
+            "allow" => Ok(OversizePolicy::Allow),
+            _ => Err(format!("unknown oversize policy: {s}")),
+        }
+    }
+}
+
+impl OversizePolicy {
+    /// Returns the grid size to use when fitting a pattern needs a `width`×`height` grid.
+    pub fn grid_size(self, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            // Even when allowed, a grid can't go past the cell limit
+            OversizePolicy::Allow
+                if Grid::check_dimensions(width, height, DEFAULT_MAX_CELLS).is_ok() =>
+            {
+                (width, height)
+            }
+            OversizePolicy::Clip | OversizePolicy::Allow => {
+                (width.min(MAX_GRID_WIDTH), height.min(MAX_GRID_HEIGHT))
+            }
+        }
+    }
+}
+
+/// What happens when the last live cells die while the simulation runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExtinctionPolicy {
+    /// Keep stepping the empty board.
+    #[default]
+    Continue,
+    /// Pause the simulation.
+    Pause,
+    /// Randomize the grid with the fill density and keep running.
+    Reseed,
+    /// Quit the app.
+    Quit,
+}
+
+impl FromStr for ExtinctionPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "continue" => Ok(ExtinctionPolicy::Continue),
+            "pause" => Ok(ExtinctionPolicy::Pause),
+            "reseed" => Ok(ExtinctionPolicy::Reseed),
+            "quit" => Ok(ExtinctionPolicy::Quit),
+            _ => Err(format!("unknown extinction policy: {s}")),
+        }
+    }
+}
+
+/// A piece of information the status bar can show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatusField {
+    /// Whether the simulation is running, paused, or paused for an edit.
+    State,
+    /// Current generation.
+    Generation,
+    /// Running time since the last reset.
+    Time,
+    /// Average generations per second since the last reset.
+    Rate,
+    /// Live cells, with the last step's net growth while running.
+    Population,
+    /// Age of the oldest live cell.
+    Oldest,
+    /// Birth and survival rule.
+    Rule,
+    /// Grid dimensions.
+    Size,
+    /// Tick interval and speed preset, or turbo.
+    Speed,
+    /// Generation rate limit, while on.
+    Limit,
+    /// Rendering mode.
+    Mode,
+    /// Drawing brush radius and whether it paints walls.
+    Brush,
+    /// Selected rectangle, if any.
+    Selection,
+    /// Generations recorded, while recording.
+    Recording,
+    /// Run target, if any.
+    Target,
+    /// Name of the pattern on the board, if recognized.
+    Pattern,
+    /// Spaceship speed and heading, if one is detected.
+    Spaceship,
+    /// Period of a repeating board, if one is detected.
+    Period,
+    /// Fraction of cells alive.
+    Density,
+    /// Cells that changed in the last step.
+    Activity,
+    /// Number of separate groups of live cells.
+    Clusters,
+    /// Fingerprint of the live cells.
+    Fingerprint,
+}
+
+impl StatusField {
+    /// Fields shown by default, in order.
+    pub const DEFAULT: &[StatusField] = &[
+        StatusField::State,
+        StatusField::Generation,
+        StatusField::Time,
+        StatusField::Rate,
+        StatusField::Population,
+        StatusField::Oldest,
+        StatusField::Rule,
+        StatusField::Size,
+        StatusField::Speed,
+        StatusField::Limit,
+        StatusField::Mode,
+        StatusField::Brush,
+        StatusField::Selection,
+        StatusField::Recording,
+        StatusField::Target,
+        StatusField::Pattern,
+        StatusField::Spaceship,
+    ];
+
+    /// Parses a comma-separated list of field names, e.g. `gen,pop,period`.
+    pub fn parse_list(s: &str) -> Result<Vec<Self>, String> {
+        if s.trim().is_empty() {
+            return Err("list at least one status field".to_string());
+        }
+        s.split(',').map(|name| name.trim().parse()).collect()
+    }
+}
+
+impl FromStr for StatusField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "state" => Ok(StatusField::State),
+            "gen" => Ok(StatusField::Generation),
+            "time" => Ok(StatusField::Time),
+            "rate" => Ok(StatusField::Rate),
+            "pop" => Ok(StatusField::Population),
+            "oldest" => Ok(StatusField::Oldest),
+            "rule" => Ok(StatusField::Rule),
+            "size" => Ok(StatusField::Size),
+            "speed" => Ok(StatusField::Speed),
+            "limit" => Ok(StatusField::Limit),
+            "mode" => Ok(StatusField::Mode),
+            "brush" => Ok(StatusField::Brush),
+            "selection" => Ok(StatusField::Selection),
+            "recording" => Ok(StatusField::Recording),
+            "target" => Ok(StatusField::Target),
+            "pattern" => Ok(StatusField::Pattern),
+            "spaceship" => Ok(StatusField::Spaceship),
+            "period" => Ok(StatusField::Period),
+            "density" => Ok(StatusField::Density),
+            "activity" => Ok(StatusField::Activity),
+            "clusters" => Ok(StatusField::Clusters),
+            "fingerprint" => Ok(StatusField::Fingerprint),
+            _ => Err(format!("unknown status field: {s}")),
+        }
+    }
+}
+
 /// Application settings for configuring behavior.
 #[derive(Debug, Clone)]
 pub struct AppSettings {
     /// Density of alive cells when randomizing (0.0 to 1.0)
     pub fill_density: f32,
+    /// Number of generations a dead cell's trail stays visible
+    pub trail_length: u16,
+    /// Fraction of all cells alive above which a population warning is shown (0.0 to 1.0)
+    pub alert_density: f32,
+    /// Net population growth per generation above which a population warning is shown
+    pub alert_growth: usize,
+    /// Whether growing the grid randomizes the newly exposed area instead of leaving it empty
+    pub randomize_on_grow: bool,
+    /// Shape of the area painted by the drawing brush
+    pub brush_shape: BrushShape,
+    /// Number of random cells toggled by a perturbation
+    pub perturb_count: usize,
+    /// Number of generations run before pausing again by the run key
+    pub run_length: u64,
+    /// Most generations `:until` runs before giving up on its condition
+    pub until_limit: u64,
+    /// Generations per second the simulation is held to while the rate limit is on
+    pub max_rate: u32,
+    /// Dead cells left around a pattern when fitting the grid to it
+    pub fit_margin: usize,
+    /// Whether to randomize the grid once it dies out or settles into a still life or oscillator
+    pub auto_reseed: bool,
+    /// Generations a still life or oscillator is left running before an automatic reseed
+    pub reseed_delay: u64,
+    /// Color of live cells
+    pub live_color: Color,
+    /// Color behind dead cells, or [`Color::Reset`] for the terminal's own background
+    pub background: Color,
+    /// What fitting the grid to a pattern larger than the maximum grid size does
+    pub oversize: OversizePolicy,
+    /// What happens when the last live cells die while running
+    pub on_extinction: ExtinctionPolicy,
+    /// Whether drawing or pasting on a running board pauses it until resumed with space
+    pub pause_on_edit: bool,
+    /// Whether quitting saves the board to the autosave file, if there is one
+    pub autosave_on_quit: bool,
+    /// Fields shown in the status bar, in order
+    pub status_fields: Vec<StatusField>,
+    /// Color cells that were just born or died flash in while highlighting is on
+    pub sparkle_color: Color,
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
             fill_density: 0.3, // for randomizer
+            trail_length: 8,
+            alert_density: 0.5,
+            alert_growth: 500,
+            randomize_on_grow: false,
+            brush_shape: BrushShape::Square,
+            perturb_count: 5,
+            run_length: 50,
+            until_limit: 10_000,
+            max_rate: 10,
+            fit_margin: 2,
+            auto_reseed: false,
+            reseed_delay: 100,
+            live_color: Color::White,
+            background: Color::Reset,
+            oversize: OversizePolicy::Clip,
+            on_extinction: ExtinctionPolicy::Continue,
+            pause_on_edit: true,
+            autosave_on_quit: true,
+            status_fields: StatusField::DEFAULT.to_vec(),
+            sparkle_color: Color::Yellow,
         }
     }
 }
 
+impl AppSettings {
+    /// Sets a setting by name from its string value.
+    ///
+    /// Returns a message describing the problem if the name or value is invalid.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        fn parse<T: FromStr>(value: &str) -> Result<T, String> {
+            value.parse().map_err(|_| format!("invalid value: {value}"))
+        }
+        fn parse_fraction(value: &str) -> Result<f32, String> {
+            let fraction = parse(value)?;
+            if !(0.0..=1.0).contains(&fraction) {
+                return Err("value must be within 0.0 to 1.0".to_string());
+            }
+            Ok(fraction)
+        }
+
+        match name {
+            "fill_density" => self.fill_density = parse_fraction(value)?,
+            "trail_length" => self.trail_length = parse(value)?,
+            "alert_density" => self.alert_density = parse_fraction(value)?,
+            "alert_growth" => self.alert_growth = parse(value)?,
+            "randomize_on_grow" => self.randomize_on_grow = parse(value)?,
+            "brush_shape" => self.brush_shape = parse(value)?,
+            "perturb_count" => self.perturb_count = parse(value)?,
+            "run_length" => match parse(value)? {
+                0 => return Err("value must be positive".to_string()),
+                run_length => self.run_length = run_length,
+            },
+            "until_limit" => match parse(value)? {
+                0 => return Err("value must be positive".to_string()),
+                until_limit => self.until_limit = until_limit,
+            },
+            "max_rate" => match parse(value)? {
+                0 => return Err("value must be positive".to_string()),
+                max_rate => self.max_rate = max_rate,
+            },
+            "fit_margin" => self.fit_margin = parse_fit_margin(value)?,
+            "auto_reseed" => self.auto_reseed = parse(value)?,
+            "reseed_delay" => self.reseed_delay = parse(value)?,
+            "live_color" => self.live_color = parse(value)?,
+            "background" => self.background = parse(value)?,
+            "oversize" => self.oversize = parse(value)?,
+            "on_extinction" => self.on_extinction = parse(value)?,
+            "pause_on_edit" => self.pause_on_edit = parse(value)?,
+            "autosave_on_quit" => self.autosave_on_quit = parse(value)?,
+            "status_fields" => self.status_fields = StatusField::parse_list(value)?,
+            "sparkle_color" => self.sparkle_color = parse(value)?,
+            _ => return Err(format!("unknown setting: {name}")),
+        }
+        Ok(())
+    }
+}
+
+/// A jump to a distant generation being computed on a worker thread.
+#[derive(Debug)]
+pub(crate) struct Jump {
+    /// Generation being jumped to, or given up at if `until` never holds.
+    pub(crate) target: u64,
+    /// Condition that ends the jump early, if it was started by `:until`.
+    pub(crate) until: Option<StopCondition>,
+    /// Bounds of the live cells when the jump started, for checking `until`.
+    start_bounds: Option<(usize, usize, usize, usize)>,
+    /// Generations stepped so far.
+    pub(crate) done: u64,
+    /// Generations to step in total.
+    pub(crate) total: u64,
+    /// Set to stop the worker early.
+    cancel: Arc<AtomicBool>,
+    /// Worker stepping the game, which hands it back, step hook included, once it stops.
+    worker: JoinHandle<Box<Game>>,
+}
+
+/// Playback of a recorded run, shown in place of the simulation.
+#[derive(Debug)]
+pub(crate) struct Replay {
+    /// Run being played back.
+    pub(crate) recording: Recording,
+    /// Generations into the recording currently shown.
+    pub(crate) position: u64,
+    /// Board from before the replay, restored when it ends.
+    saved: GameSnapshot,
+    /// Rule from before the replay, restored when it ends.
+    saved_rule: Rule,
+    /// Whether the simulation was paused before the replay.
+    was_paused: bool,
+}
+
 /// Main application state and control logic.
 pub struct App {
     /// The game logic and grid state.
@@ -32,6 +446,85 @@ pub struct App {
     should_quit: bool,
     /// User configurable settings.
     pub settings: AppSettings,
+    /// Whether to draw row/column rulers around the grid.
+    pub show_ruler: bool,
+    /// Whether to draw fading trails behind dead cells.
+    pub show_trails: bool,
+    /// Whether to highlight cells that were born or died in the latest generation.
+    pub show_sparkle: bool,
+    /// How grid cells are packed into terminal characters.
+    pub render_mode: RenderMode,
+    /// Width of each cell in terminal characters in block rendering.
+    pub cell_width: usize,
+    /// Whether the grid size was set manually, so terminal resizes leave it alone.
+    pub manual_size: bool,
+    /// Whether the grid size is locked, so terminal resizes and cell width changes leave it
+    /// alone however it was sized.
+    pub size_locked: bool,
+    /// Whether to show the statistics overlay.
+    pub show_stats: bool,
+    /// Whether to show the key binding overlay.
+    pub show_help: bool,
+    /// Whether the simulation runs as fast as possible instead of at the tick interval.
+    pub turbo: bool,
+    /// Whether the simulation is held to at most `settings.max_rate` generations per second.
+    pub rate_limited: bool,
+    /// When the simulation last stepped, for enforcing the rate limit.
+    last_advance: Option<Instant>,
+    /// Radius of the drawing brush in cells (`0` paints a single cell).
+    pub brush_radius: usize,
+    /// Whether the brush paints walls instead of cells.
+    pub wall_mode: bool,
+    /// Whether the mouse inspects cells instead of drawing them.
+    pub inspect: bool,
+    /// Whether the simulation was paused by an edit and hasn't been resumed since.
+    pub editing: bool,
+    /// Selected rectangle as (top, left, height, width), which region commands act on.
+    pub selection: Option<(usize, usize, usize, usize)>,
+    /// Whether the mouse and arrow keys select a rectangle instead of drawing and changing speed.
+    pub select_mode: bool,
+    /// Corner of the selection being made, opposite the cursor, if one is being made.
+    select_anchor: Option<(usize, usize)>,
+    /// Cell moved by the arrow keys in select mode, as (row, col).
+    pub select_cursor: (usize, usize),
+    /// Whether the arrow keys shift the whole board instead of changing speed.
+    pub move_mode: bool,
+    /// In-memory quick-save slots.
+    slots: [Option<GameSnapshot>; SAVE_SLOTS],
+    /// Board as it was last loaded or generated, for starting the experiment over.
+    loaded: GameSnapshot,
+    /// Name of the loaded pattern or scene shown as the grid's title, if any.
+    pub title: Option<String>,
+    /// Text typed at the `:` prompt, or `None` when the prompt is closed.
+    pub command_input: Option<String>,
+    /// Feedback message shown in the status bar until the next key press.
+    pub status_message: Option<String>,
+    /// RLE of the pattern last copied with `y`, pasted back by `Ctrl+V`.
+    copied: Option<String>,
+    /// Background jump in progress, if any; the board is left alone until it finishes.
+    pub(crate) jump: Option<Jump>,
+    /// [`Event::Jumped`] notices still to come from cancelled jumps, which are skipped.
+    stale_jump_notices: usize,
+    /// Run being recorded, or the last one recorded, if any.
+    pub recording: Option<Recording>,
+    /// Whether each generation is being added to `recording`.
+    pub is_recording: bool,
+    /// Replay in progress, if any; the board can't be edited until it ends.
+    pub(crate) replay: Option<Replay>,
+    /// Screen area the grid was last drawn in, used to map mouse positions to cells.
+    pub(crate) grid_area: Cell<Option<Rect>>,
+    /// CSV file each generation's population data is appended to, if any.
+    pub csv_log: Option<CsvLog>,
+    /// File the board is saved to on quit if `settings.autosave_on_quit` is set, if any.
+    pub autosave_path: Option<PathBuf>,
+    /// Why the autosave on quit failed, if it did, for reporting once the terminal is restored.
+    pub autosave_error: Option<String>,
+    /// Throughput log written while the simulation runs, if enabled.
+    #[cfg(feature = "log")]
+    pub metrics_log: Option<MetricsLog>,
+    /// Server each generation is streamed to, if enabled.
+    #[cfg(feature = "stream")]
+    pub stream: Option<StreamServer>,
 }
 
 impl App {
@@ -40,10 +533,17 @@ impl App {
         Self::default()
     }
 
+    /// Returns a builder for configuring a new application instance.
+    pub fn builder() -> AppBuilder {
+        AppBuilder::default()
+    }
+
     /// Runs the application's main loop until the user quits.
-    pub fn run(mut self, mut terminal: DefaultTerminal) -> color_eyre::Result<()> {
+    pub fn run(&mut self, mut terminal: DefaultTerminal) -> color_eyre::Result<()> {
+        self.events
+            .start(self.game.tick_interval, self.game.is_paused());
         while !self.should_quit {
-            terminal.draw(|frame| frame.render_widget(&self, frame.area()))?;
+            terminal.draw(|frame| frame.render_widget(&*self, frame.area()))?;
             self.handle_events()?;
         }
         Ok(())
@@ -52,83 +552,1725 @@ impl App {
     /// Processes all pending events and updates application state.
     fn handle_events(&mut self) -> color_eyre::Result<()> {
         match self.events.next()? {
-            Event::Tick => self.game.step(),
+            // The board is about to be replaced by the jump's result, so leave it be until then
+            Event::Tick if self.jump.is_some() => {}
+            Event::Tick if self.replay.is_some() => self.advance_replay(),
+            // A tick queued before the game paused itself (e.g. at the end of a countdown) must
+            // not step past where it stopped
+            Event::Tick if self.game.is_paused() => {}
+            Event::Tick => {
+                // In turbo mode, keep stepping until it's time to draw the next frame
+                let frame_start = Instant::now();
+                while self.step_due() {
+                    self.advance();
+                    if !self.turbo
+                        || self.game.is_paused()
+                        || self.should_quit
+                        || frame_start.elapsed() >= TURBO_FRAME
+                    {
+                        break;
+                    }
+                }
+            }
             Event::Crossterm(event) => match event {
                 crossterm::event::Event::Key(key_event) => self.handle_key_event(key_event)?,
-                crossterm::event::Event::Resize(w, h) => {
-                    let (new_grid_width, new_grid_height) = calculate_grid_size((w, h));
-                    self.game.resize(new_grid_width, new_grid_height);
+                crossterm::event::Event::Mouse(mouse_event)
+                    if self.jump.is_none() && self.replay.is_none() =>
+                {
+                    self.handle_mouse_event(mouse_event);
+                }
+                crossterm::event::Event::Paste(text)
+                    if self.jump.is_none() && self.replay.is_none() =>
+                {
+                    self.handle_paste(&text);
+                }
+                crossterm::event::Event::Resize(w, h) if self.follows_terminal() => {
+                    self.fit_grid_to_terminal((w, h));
                 }
                 _ => {}
             },
+            Event::Progress(done, total) => {
+                if let Some(jump) = &mut self.jump {
+                    jump.done = done;
+                    jump.total = total;
+                }
+            }
+            // Every worker sends one notice, so a cancelled jump's can arrive while a newer one
+            // is still running
+            Event::Jumped if self.stale_jump_notices > 0 => self.stale_jump_notices -= 1,
+            Event::Jumped => {
+                if let Some(jump) = self.jump.take() {
+                    let mut game = jump
+                        .worker
+                        .join()
+                        .map_err(|_| color_eyre::eyre::eyre!("background jump panicked"))?;
+                    if let Some(condition) = jump.until {
+                        self.status_message = Some(if condition.is_met(&game, jump.start_bounds) {
+                            format!("{condition} at generation {}", game.generation)
+                        } else {
+                            format!("no {condition} within {} generations", jump.total)
+                        });
+                    }
+                    // Keep any resize made while the jump was running
+                    game.resize(self.game.grid.width(), self.game.grid.height());
+                    self.game = *game;
+                    if jump.until.is_some() {
+                        self.set_paused(true);
+                    }
+                }
+            }
             Event::App(app_event) => match app_event {
-                AppEvent::Randomize => self.game.randomize(self.settings.fill_density),
-                AppEvent::Clear => self.game.clear(),
+                AppEvent::Randomize => self.randomize(),
+                AppEvent::Clear => {
+                    self.game.clear();
+                    self.title = None;
+                    self.loaded = self.game.snapshot();
+                }
                 AppEvent::Quit => self.quit(),
             },
         }
         Ok(())
     }
 
+    /// Returns true unless the rate limit is on and the simulation stepped too recently.
+    fn step_due(&self) -> bool {
+        let min_gap = Duration::from_secs_f64(1.0 / f64::from(self.settings.max_rate));
+        !self.rate_limited
+            || self
+                .last_advance
+                .is_none_or(|last_advance| last_advance.elapsed() >= min_gap)
+    }
+
+    /// Steps the simulation once, recording logs, pausing at the run target, and handling the
+    /// board dying out.
+    fn advance(&mut self) {
+        self.last_advance = Some(Instant::now());
+        let was_alive = !self.game.grid.is_empty();
+        // Edits and jumps since the last step can't be replayed, so the recording ends before them
+        if self.is_recording
+            && let Some(recording) = &self.recording
+            && !recording.follows(&self.game)
+        {
+            self.is_recording = false;
+            self.status_message = Some(format!(
+                "recording stopped at {} generations: the board was changed",
+                recording.len()
+            ));
+        }
+        #[cfg(feature = "log")]
+        let started = Instant::now();
+        self.game.step();
+        #[cfg(feature = "log")]
+        if let Some(log) = &mut self.metrics_log {
+            log.record(&self.game, started.elapsed());
+        }
+        #[cfg(feature = "stream")]
+        if let Some(stream) = &mut self.stream {
+            stream.send(&self.game);
+        }
+        if self.is_recording
+            && let Some(recording) = &mut self.recording
+            && !recording.record(&self.game)
+        {
+            self.is_recording = false;
+            self.status_message = Some(format!(
+                "recording stopped at {MAX_RECORDING_LENGTH} generations"
+            ));
+        }
+        if let (Some(csv_log), Some(entry)) = (&mut self.csv_log, self.game.history.latest()) {
+            csv_log.record(entry);
+        }
+        if was_alive && self.game.grid.is_empty() {
+            self.handle_extinction();
+        }
+        if self.game.reached_run_target() {
+            self.game.run_target = None;
+            self.set_paused(true);
+        }
+        // A finished countdown pauses the game itself, which the event thread must follow
+        if self.game.is_paused() {
+            self.set_paused(true);
+        }
+        if self.settings.auto_reseed && self.settled() {
+            self.randomize();
+            self.status_message = Some("reseeding…".to_string());
+        }
+    }
+
+    /// Applies `settings.on_extinction` after a step killed the last live cells.
+    fn handle_extinction(&mut self) {
+        match self.settings.on_extinction {
+            ExtinctionPolicy::Continue => {}
+            ExtinctionPolicy::Pause => {
+                self.set_paused(true);
+                self.status_message =
+                    Some(format!("died out at generation {}", self.game.generation));
+            }
+            ExtinctionPolicy::Reseed => {
+                self.randomize();
+                self.status_message = Some("died out, reseeding…".to_string());
+            }
+            ExtinctionPolicy::Quit => self.quit(),
+        }
+    }
+
+    /// Steps the replay forward once, pausing at the end of the recording.
+    fn advance_replay(&mut self) {
+        let Some(replay) = &mut self.replay else {
+            return;
+        };
+        if replay.position < replay.recording.len() {
+            replay.position += 1;
+            self.game.step();
+        }
+        if replay.position == replay.recording.len() {
+            self.set_paused(true);
+        }
+    }
+
+    /// Starts playing back the last recording, stopping the recording first if it is running.
+    fn start_replay(&mut self) {
+        self.is_recording = false;
+        let Some(recording) = self.recording.clone().filter(|r| !r.is_empty()) else {
+            self.status_message = Some("nothing recorded".to_string());
+            return;
+        };
+        let (saved, saved_rule) = (self.game.snapshot(), self.game.rule);
+        self.game.run_target = None;
+        self.game.pause_after = None;
+        recording.seek(&mut self.game, 0);
+        self.replay = Some(Replay {
+            recording,
+            position: 0,
+            saved,
+            saved_rule,
+            was_paused: self.game.is_paused(),
+        });
+        self.set_paused(false);
+    }
+
+    /// Shows the replay `position` generations into the recording, clamped to its length, and
+    /// pauses it there.
+    fn seek_replay(&mut self, position: u64) {
+        if let Some(replay) = &mut self.replay {
+            replay.position = position.min(replay.recording.len());
+            replay.recording.seek(&mut self.game, replay.position);
+            self.set_paused(true);
+        }
+    }
+
+    /// Ends the replay, restoring the board, rule, and pause state from before it started.
+    fn end_replay(&mut self) {
+        if let Some(replay) = self.replay.take() {
+            self.game.restore(&replay.saved);
+            self.game.rule = replay.saved_rule;
+            self.set_paused(replay.was_paused);
+        }
+    }
+
+    /// Handles a key during a replay.
+    ///
+    /// Returns true if the key was handled, or isn't available during a replay, so that only
+    /// playback, speed, and view controls get through to the usual handling.
+    fn handle_replay_key(&mut self, code: KeyCode) -> bool {
+        let Some(position) = self.replay.as_ref().map(|replay| replay.position) else {
+            return false;
+        };
+        match code {
+            KeyCode::Esc => self.end_replay(),
+            KeyCode::Left => self.seek_replay(position.saturating_sub(1)),
+            KeyCode::Right => self.seek_replay(position + 1),
+            KeyCode::Home => self.seek_replay(0),
+            KeyCode::End => self.seek_replay(u64::MAX),
+            KeyCode::Up | KeyCode::Down | KeyCode::F(_) => return false,
+            KeyCode::Char(' ' | 'q' | 'g' | 'x' | 'a' | 's' | 'w' | '=' | '-' | '?') => {
+                return false;
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Returns true if the grid has died out, or has repeated a period for at least
+    /// `settings.reseed_delay` generations.
+    fn settled(&self) -> bool {
+        self.game.grid.is_empty()
+            || self.game.stabilized_at().is_some_and(|stabilized_at| {
+                self.game.generation - stabilized_at >= self.settings.reseed_delay
+            })
+    }
+
+    /// Randomizes the grid with the fill density, making it the board to reset to.
+    fn randomize(&mut self) {
+        self.game.randomize(self.settings.fill_density);
+        self.title = None;
+        self.loaded = self.game.snapshot();
+    }
+
     /// Processes keyboard input.
     ///
     /// # Keybinds
     ///
-    /// `Esc` or `q`: Quit the application
+    /// `Esc`, `q`, or `Ctrl` + `c`: Quit the application
     /// `Space`: Toggle pause/resume
     /// `Up`: Increase simulation tick interval
     /// `Down`: Decrease simulation tick interval
+    /// `F1`-`F5`: Jump to a speed preset, slowest to fastest
     /// `r`: Randomize grid
+    /// `R`: Reset to the board as it was loaded
     /// `c`: Clear grid
+    /// `0`: Make the current state generation 0
+    /// `k`: Toggle a few random cells
+    /// `n`: Run `settings.run_length` generations, then pause
+    /// `g`: Toggle coordinate ruler
+    /// `x`: Toggle trails
+    /// `a`: Toggle highlighting cells that just changed
+    /// `s`: Toggle statistics overlay
+    /// `t`: Toggle turbo mode
+    /// `l`: Toggle the generation rate limit
+    /// `w`: Cycle cell width
+    /// `L`: Toggle locking the grid size
+    /// `=`/`-`: Zoom in/out between rendering densities
+    /// `[`/`]`: Shrink/grow the drawing brush
+    /// `b`: Toggle painting walls instead of cells
+    /// `1`-`9`: Load quick-save slot
+    /// `Alt` + `1`-`9`: Save to quick-save slot
+    /// `:`: Open the command prompt
+    /// `y`: Copy the live cells to the clipboard as RLE
+    /// `Ctrl` + `v`: Paste the pattern last copied with `y`
+    /// `i`: Toggle inspecting cells with the mouse
+    /// `f`: Show the fingerprint of the live cells
+    /// `v`: Toggle select mode
+    /// `m`: Toggle moving the board with the arrow keys
+    /// `?`: Show all key bindings until the next key press
+    /// `,`: Pause and step back one generation in reversible mode
+    ///
+    /// With a selection, `Esc` clears it instead of quitting. In select mode, the arrow keys move
+    /// the cursor and `Enter` starts or finishes a selection at it. In move mode, the arrow keys
+    /// shift the board by a cell and `Enter` or `Esc` leaves the mode.
+    ///
+    /// While a `goto` or `until` runs in the background, only `Esc` (to cancel it) and `Ctrl` +
+    /// `c` work.
+    ///
+    /// During a replay, `Left`/`Right` step through it, `Home`/`End` jump to its start or end,
+    /// and `Esc` ends it. Keys that would edit the board are ignored.
     fn handle_key_event(&mut self, key_event: KeyEvent) -> color_eyre::Result<()> {
-        match key_event.code {
-            KeyCode::Esc | KeyCode::Char('q') => self.events.send(AppEvent::Quit),
+        self.status_message = None;
+
+        const NONE: KeyModifiers = KeyModifiers::NONE;
+        const CONTROL: KeyModifiers = KeyModifiers::CONTROL;
+
+        // Shift is already reflected in the character (e.g. `R` or `:`), so only match it for
+        // non-character keys
+        let modifiers = match key_event.code {
+            KeyCode::Char(_) => key_event.modifiers.difference(KeyModifiers::SHIFT),
+            _ => key_event.modifiers,
+        };
+
+        // Raw mode turns Ctrl+C into a key press instead of a signal, so handle it everywhere
+        if (key_event.code, modifiers) == (KeyCode::Char('c'), CONTROL) {
+            self.events.send(AppEvent::Quit);
+            return Ok(());
+        }
+
+        if self.command_input.is_some() {
+            self.handle_command_key(key_event);
+            return Ok(());
+        }
 
-            KeyCode::Up => {
-                self.events.set_tick_interval(self.game.inc_interval());
+        if self.jump.is_some() {
+            if key_event.code == KeyCode::Esc {
+                self.cancel_jump();
             }
-            KeyCode::Down => {
-                self.events.set_tick_interval(self.game.dec_interval());
+            return Ok(());
+        }
+
+        if self.show_help {
+            self.show_help = false;
+            return Ok(());
+        }
+
+        if modifiers == NONE && self.handle_replay_key(key_event.code) {
+            return Ok(());
+        }
+
+        if self.select_mode && modifiers == NONE && self.handle_select_key(key_event.code) {
+            return Ok(());
+        }
+
+        if self.move_mode && modifiers == NONE && self.handle_move_key(key_event.code) {
+            return Ok(());
+        }
+
+        if let Some((action, index)) = keymap::lookup(key_event.code, modifiers) {
+            self.perform(action, index);
+        }
+        Ok(())
+    }
+
+    /// Carries out the action of a key binding, where `index` numbers the key pressed among
+    /// the binding's keys (e.g. the slot for [`Action::LoadSlot`]).
+    fn perform(&mut self, action: Action, index: usize) {
+        match action {
+            Action::LoadSlot => self.load_slot(index),
+            Action::SaveSlot => self.save_slot(index),
+            Action::Escape if self.select_mode || self.selection.is_some() => {
+                self.selection = None;
+                self.select_mode = false;
+                self.select_anchor = None;
+            }
+            Action::Escape | Action::Quit => self.events.send(AppEvent::Quit),
+            Action::SlowDown => {
+                self.game.inc_interval();
+                self.sync_tick_interval();
+            }
+            Action::SpeedUp => {
+                self.game.dec_interval();
+                self.sync_tick_interval();
+            }
+            Action::SpeedPreset => {
+                self.game.set_speed_preset(index);
+                self.turbo = false;
+                self.sync_tick_interval();
+            }
+            Action::ToggleTurbo => {
+                self.turbo = !self.turbo;
+                self.sync_tick_interval();
+            }
+            Action::ToggleRateLimit => self.rate_limited = !self.rate_limited,
+            Action::TogglePause => {
+                // Pausing manually cancels any run target or countdown
+                self.game.run_target = None;
+                self.game.pause_after = None;
+                self.set_paused(!self.game.is_paused());
+            }
+            Action::OpenPrompt => self.command_input = Some(String::new()),
+            Action::Yank => self.yank(),
+            Action::Fingerprint => {
+                self.status_message =
+                    Some(format!("fingerprint: {}", self.game.grid.fingerprint()));
+            }
+            Action::ToggleSelectMode => {
+                self.select_mode = !self.select_mode;
+                self.select_anchor = None;
+                self.move_mode = false;
+                if self.select_mode {
+                    let (width, height) = self.game.grid.dimensions();
+                    self.select_cursor = (height / 2, width / 2);
+                    self.status_message = Some(
+                        "select: drag, or move with the arrows and press Enter at each corner"
+                            .to_string(),
+                    );
+                }
+            }
+            Action::ToggleMoveMode => {
+                self.move_mode = !self.move_mode;
+                self.select_mode = false;
+                if self.move_mode {
+                    self.status_message = Some(MOVE_HINT.to_string());
+                }
+            }
+            Action::ToggleInspect => {
+                self.inspect = !self.inspect;
+                if self.inspect {
+                    self.status_message = Some("inspect: point at a cell".to_string());
+                }
             }
-            KeyCode::Char(' ') => {
-                self.game.toggle_pause();
-                if self.game.is_paused() {
-                    self.events.pause();
+            Action::Paste => match clipboard::paste().or_else(|| self.copied.clone()) {
+                Some(text) => self.handle_paste(&text),
+                // Without the `clipboard` feature the system clipboard can't be read, but the
+                // terminal's own paste key sends it
+                None => {
+                    self.status_message = Some(
+                        "nothing copied yet: paste other patterns with your terminal's paste key"
+                            .to_string(),
+                    );
+                }
+            },
+            Action::StepBack => {
+                if !self.game.is_reversible() {
+                    self.status_message = Some("stepping back needs reversible mode".to_string());
                 } else {
-                    self.events.resume();
+                    self.game.run_target = None;
+                    self.game.pause_after = None;
+                    self.set_paused(true);
+                    if !self.game.step_back_reversible() {
+                        self.status_message = Some("already at generation 0".to_string());
+                    }
+                }
+            }
+            Action::Randomize => self.events.send(AppEvent::Randomize),
+            Action::ResetToLoaded => self.game.restore(&self.loaded),
+            Action::Clear => self.events.send(AppEvent::Clear),
+            Action::ResetGeneration => {
+                self.game.reset_generation();
+                self.status_message = Some("generation reset to 0".to_string());
+            }
+            Action::Perturb => self.game.perturb(self.settings.perturb_count, None),
+            Action::RunFor => self.run_for(self.settings.run_length),
+            Action::ToggleRuler => self.show_ruler = !self.show_ruler,
+            Action::ToggleTrails => self.show_trails = !self.show_trails,
+            Action::ToggleSparkle => self.show_sparkle = !self.show_sparkle,
+            Action::ToggleStats => self.show_stats = !self.show_stats,
+            Action::ShowHelp => self.show_help = true,
+            Action::CycleCellWidth => self.cycle_cell_width(),
+            Action::ToggleSizeLock => self.toggle_size_lock(),
+            Action::ZoomIn => self.render_mode = self.render_mode.zoom_in(),
+            Action::ZoomOut => self.render_mode = self.render_mode.zoom_out(),
+            Action::ToggleWallMode => self.wall_mode = !self.wall_mode,
+            Action::ShrinkBrush => self.brush_radius = self.brush_radius.saturating_sub(1),
+            Action::GrowBrush => {
+                self.brush_radius = (self.brush_radius + 1).min(MAX_BRUSH_RADIUS);
+            }
+        }
+    }
+
+    /// Handles a key that means something else in select mode, returning true if it was used.
+    fn handle_select_key(&mut self, code: KeyCode) -> bool {
+        let (width, height) = self.game.grid.dimensions();
+        let (row, col) = self.select_cursor;
+        let (row, col) = (row.min(height - 1), col.min(width - 1));
+        match code {
+            KeyCode::Up => self.select_to(row.saturating_sub(1), col),
+            KeyCode::Down => self.select_to((row + 1).min(height - 1), col),
+            KeyCode::Left => self.select_to(row, col.saturating_sub(1)),
+            KeyCode::Right => self.select_to(row, (col + 1).min(width - 1)),
+            KeyCode::Enter => {
+                if self.select_anchor.take().is_none() {
+                    self.select_anchor = Some((row, col));
+                    self.select_to(row, col);
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Handles a key in move mode, returning true if it was used.
+    ///
+    /// The arrow keys shift the board by a cell, wrapping around the edges the grid wraps on,
+    /// and `Enter` or `Esc` leaves the mode.
+    fn handle_move_key(&mut self, code: KeyCode) -> bool {
+        let (wrap_x, wrap_y) = (self.game.grid.wrap_x(), self.game.grid.wrap_y());
+        let (d_row, d_col, wrap) = match code {
+            KeyCode::Up => (-1, 0, wrap_y),
+            KeyCode::Down => (1, 0, wrap_y),
+            KeyCode::Left => (0, -1, wrap_x),
+            KeyCode::Right => (0, 1, wrap_x),
+            KeyCode::Enter | KeyCode::Esc => {
+                self.move_mode = false;
+                return true;
+            }
+            _ => return false,
+        };
+        self.pause_for_edit();
+        self.game.grid.translate(d_row, d_col, wrap);
+        self.status_message = Some(MOVE_HINT.to_string());
+        true
+    }
+
+    /// Moves the select cursor to a cell, stretching the selection being made, if any, from
+    /// its anchor to there.
+    fn select_to(&mut self, row: usize, col: usize) {
+        self.select_cursor = (row, col);
+        if let Some((anchor_row, anchor_col)) = self.select_anchor {
+            let (top, left) = (anchor_row.min(row), anchor_col.min(col));
+            let (bottom, right) = (anchor_row.max(row), anchor_col.max(col));
+            self.selection = Some((top, left, bottom - top + 1, right - left + 1));
+        }
+    }
+
+    /// Returns the selection clipped to the grid, or `None` if there is none or none of it is
+    /// left after resizing.
+    pub fn active_selection(&self) -> Option<(usize, usize, usize, usize)> {
+        let (top, left, height, width) = self.selection?;
+        let bottom = (top + height).min(self.game.grid.height());
+        let right = (left + width).min(self.game.grid.width());
+        (top < bottom && left < right).then(|| (top, left, bottom - top, right - left))
+    }
+
+    /// Copies the bounding box of the live cells to the clipboard as RLE.
+    fn yank(&mut self) {
+        let Some((top, left, height, width)) = self.game.grid.live_bounds() else {
+            self.status_message = Some("nothing to copy".to_string());
+            return;
+        };
+        let cells: Vec<_> = self
+            .game
+            .grid
+            .iter_alive_cells()
+            .map(|(row, col)| (row - top, col - left))
+            .collect();
+        let rle = patterns::to_rle(&cells, &self.game.rule);
+
+        self.status_message = Some(match clipboard::copy(&rle) {
+            Ok(()) => format!("copied {width}×{height} pattern as RLE"),
+            Err(e) => format!("failed to copy: {e}"),
+        });
+        self.copied = Some(rle);
+    }
+
+    /// Handles text pasted into the terminal.
+    ///
+    /// Goes to the command prompt if it is open, otherwise the text is parsed as RLE (or
+    /// plaintext) and stamped in the center of the grid.
+    fn handle_paste(&mut self, text: &str) {
+        if let Some(input) = &mut self.command_input {
+            input.extend(text.chars().take_while(|&c| c != '\n' && c != '\r'));
+            return;
+        }
+
+        let cells = match patterns::parse_pattern(text) {
+            Ok(cells) => cells,
+            Err(e) => {
+                self.status_message = Some(format!("failed to paste: {e}"));
+                return;
+            }
+        };
+        let (height, width) = patterns::bounding_size(&cells);
+        let top = self.game.grid.height().saturating_sub(height) / 2;
+        let left = self.game.grid.width().saturating_sub(width) / 2;
+        self.pause_for_edit();
+        let placed = self.game.grid.stamp(&cells, top, left);
+        self.status_message = Some(if placed < cells.len() {
+            format!("pasted {width}×{height} pattern (clipped to the grid)")
+        } else {
+            format!("pasted {width}×{height} pattern")
+        });
+    }
+
+    /// Sets one of the game's tick interval bounds by name from a value in milliseconds.
+    ///
+    /// Returns a message describing the problem if the value is invalid.
+    fn set_interval_bound(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let millis = value
+            .parse()
+            .map_err(|_| format!("invalid value: {value}"))?;
+        let bound = Duration::from_millis(millis);
+        let (mut min, mut max, mut step) = self.game.interval_bounds();
+        match name {
+            "min_interval" => min = bound,
+            "max_interval" => max = bound,
+            _ => step = bound,
+        }
+        self.game.set_interval_bounds(min, max, step)
+    }
+
+    /// Starts stepping a copy of the game to `target` on a worker thread, stopping early once
+    /// `until` holds after a step.
+    ///
+    /// The worker reports its progress as [`Event::Progress`] and announces that it stopped with
+    /// [`Event::Jumped`], when its game replaces the board all at once. The step hook goes with
+    /// the copy, so it runs for every jumped generation and comes back with the result.
+    fn start_jump(&mut self, target: u64, until: Option<StopCondition>) {
+        let mut game = self.game.clone();
+        if let Some(hook) = self.game.take_on_step() {
+            game.set_on_step(hook);
+        }
+        let total = target - game.generation;
+        let start_bounds = game.grid.live_bounds();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let sender = self.events.sender();
+
+        let worker_cancel = Arc::clone(&cancel);
+        let worker = thread::spawn(move || {
+            let mut last_report = Instant::now();
+            for done in 1..=total {
+                if worker_cancel.load(Ordering::Relaxed) {
+                    break;
+                }
+                game.step();
+                if until.is_some_and(|condition| condition.is_met(&game, start_bounds)) {
+                    break;
+                }
+                if last_report.elapsed() >= JUMP_PROGRESS_INTERVAL {
+                    last_report = Instant::now();
+                    let _ = sender.send(Event::Progress(done, total));
+                }
+            }
+            let _ = sender.send(Event::Jumped);
+            Box::new(game)
+        });
+
+        self.jump = Some(Jump {
+            target,
+            until,
+            start_bounds,
+            done: 0,
+            total,
+            cancel,
+            worker,
+        });
+    }
+
+    /// Stops the background jump, if any, leaving the board as it was before the jump.
+    ///
+    /// Waits for the worker to finish its current step so the step hook can be put back.
+    fn cancel_jump(&mut self) {
+        if let Some(jump) = self.jump.take() {
+            jump.cancel.store(true, Ordering::Relaxed);
+            self.stale_jump_notices += 1;
+            if let Some(hook) = jump
+                .worker
+                .join()
+                .ok()
+                .and_then(|mut game| game.take_on_step())
+            {
+                self.game.set_on_step(hook);
+            }
+            self.status_message = Some(match jump.until {
+                Some(condition) => format!("until {condition} cancelled"),
+                None => format!("goto {} cancelled", jump.target),
+            });
+        }
+    }
+
+    /// Resizes the grid to fit its live cells, capped by the oversize policy as at startup, and
+    /// reports the new size or what had to be clipped.
+    fn fit_to_pattern(&mut self, margin: Option<usize>) {
+        let margin = margin.unwrap_or(self.settings.fit_margin);
+        let Some((_, _, height, width)) = self.game.grid.live_bounds() else {
+            self.status_message = Some("nothing to fit".to_string());
+            return;
+        };
+        let needed = Game::fit_size(width, height, margin);
+        let (max_width, max_height) = self.settings.oversize.grid_size(needed.0, needed.1);
+        let population = self.game.grid.live_count();
+        self.status_message = Some(
+            match self
+                .game
+                .fit_to_pattern_within(margin, max_width, max_height)
+            {
+                Ok(_) => {
+                    self.manual_size = true;
+                    let (grid_width, grid_height) = self.game.grid.dimensions();
+                    if self.game.grid.live_count() < population {
+                        format!(
+                            "{width}×{height} pattern exceeds the \
+                             {MAX_GRID_WIDTH}×{MAX_GRID_HEIGHT} maximum, clipped to the \
+                             {grid_width}×{grid_height} grid"
+                        )
+                    } else if grid_width > MAX_GRID_WIDTH || grid_height > MAX_GRID_HEIGHT {
+                        format!(
+                            "{grid_width}×{grid_height} grid exceeds the \
+                             {MAX_GRID_WIDTH}×{MAX_GRID_HEIGHT} maximum"
+                        )
+                    } else {
+                        format!("resized grid to {grid_width}×{grid_height}")
+                    }
+                }
+                Err(e) => format!("can't fit: {e}"),
+            },
+        );
+    }
+
+    /// Returns true if the grid is sized to the terminal, neither set manually nor locked.
+    fn follows_terminal(&self) -> bool {
+        !self.manual_size && !self.size_locked
+    }
+
+    /// Locks the grid at its current size, or unlocks it and fits it to the terminal again
+    /// unless it was sized manually.
+    fn toggle_size_lock(&mut self) {
+        self.size_locked = !self.size_locked;
+        let (width, height) = self.game.grid.dimensions();
+        self.status_message = Some(if self.size_locked {
+            format!("grid size locked at {width}×{height}")
+        } else {
+            "grid size unlocked".to_string()
+        });
+        if self.follows_terminal() {
+            self.fit_grid_to_current_terminal();
+        }
+    }
+
+    /// Resizes the grid to fill a terminal of the given size.
+    fn fit_grid_to_terminal(&mut self, terminal_size: (u16, u16)) {
+        let (new_grid_width, new_grid_height) = calculate_grid_size(terminal_size, self.cell_width);
+        self.resize_grid(new_grid_width, new_grid_height);
+    }
+
+    /// Resizes the grid, randomizing any newly exposed area if enabled in the settings.
+    fn resize_grid(&mut self, new_width: usize, new_height: usize) {
+        let (old_width, old_height) = self.game.grid.dimensions();
+        self.game.resize(new_width, new_height);
+
+        if self.settings.randomize_on_grow {
+            let density = self.settings.fill_density;
+            // New columns to the right of the old area
+            if new_width > old_width {
+                let height = old_height.min(new_height);
+                self.game
+                    .randomize_region(0, old_width, height, new_width - old_width, density);
+            }
+            // New rows below the old area, spanning the full width
+            if new_height > old_height {
+                self.game.randomize_region(
+                    old_height,
+                    0,
+                    new_height - old_height,
+                    new_width,
+                    density,
+                );
+            }
+        }
+    }
+
+    /// Resizes the grid to fill the current terminal.
+    fn fit_grid_to_current_terminal(&mut self) {
+        let terminal_size = crossterm::terminal::size().unwrap_or(FALLBACK_TERMINAL_SIZE);
+        self.fit_grid_to_terminal(terminal_size);
+    }
+
+    /// Cycles the cell width between 1 and `MAX_CELL_WIDTH` characters.
+    ///
+    /// Automatically sized grids are refit to the terminal at the new width.
+    fn cycle_cell_width(&mut self) {
+        self.cell_width = self.cell_width % MAX_CELL_WIDTH + 1;
+        if self.follows_terminal() {
+            self.fit_grid_to_current_terminal();
+        }
+    }
+
+    /// Processes keyboard input while the command prompt is open.
+    ///
+    /// `Enter` runs the command, `Esc` cancels, and `Backspace` on an empty prompt closes it.
+    fn handle_command_key(&mut self, key_event: KeyEvent) {
+        let Some(input) = &mut self.command_input else {
+            return;
+        };
+        match key_event.code {
+            KeyCode::Char(c)
+                if !key_event
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                input.push(c)
+            }
+            KeyCode::Backspace if input.pop().is_none() => self.command_input = None,
+            KeyCode::Enter => {
+                let input = self.command_input.take().unwrap_or_default();
+                match Command::parse(&input) {
+                    Ok(command) => self.execute_command(command),
+                    Err(message) => self.status_message = Some(message),
                 }
             }
-            KeyCode::Char('r') => self.events.send(AppEvent::Randomize),
-            KeyCode::Char('c') => self.events.send(AppEvent::Clear),
-            // Other handlers you could add here.
+            KeyCode::Esc => self.command_input = None,
             _ => {}
         }
-        Ok(())
     }
 
-    /// Signals the application to terminate.
+    /// Carries out a command entered at the prompt.
+    fn execute_command(&mut self, command: Command) {
+        match command {
+            Command::RunTo(target) => {
+                if target <= self.game.generation {
+                    self.status_message =
+                        Some(format!("already at generation {}", self.game.generation));
+                    return;
+                }
+                self.game.run_target = Some(target);
+                self.game.pause_after = None;
+                self.set_paused(false);
+            }
+            Command::Run(generations) => self.run_for(generations),
+            Command::Goto(target) => {
+                if target <= self.game.generation {
+                    self.status_message =
+                        Some(format!("already at generation {}", self.game.generation));
+                    return;
+                }
+                self.start_jump(target, None);
+            }
+            Command::Until(condition) => {
+                if condition.is_met(&self.game, self.game.grid.live_bounds()) {
+                    self.status_message = Some(format!("already {condition}"));
+                    return;
+                }
+                let target = self.game.generation + self.settings.until_limit;
+                self.start_jump(target, Some(condition));
+            }
+            Command::Checker => {
+                self.game.fill_checkerboard();
+                self.title = Some("checkerboard".to_string());
+                self.loaded = self.game.snapshot();
+            }
+            Command::Stripes { period, vertical } => {
+                self.game.fill_stripes(period, vertical);
+                self.title = Some("stripes".to_string());
+                self.loaded = self.game.snapshot();
+            }
+            Command::FindDensity(population) => {
+                // Within 5% of the target, from at most a dozen trial soups
+                let tolerance = population / 20;
+                let density = self
+                    .game
+                    .find_density_for_stable_population(population, tolerance, 12);
+                self.settings.fill_density = density;
+                self.randomize();
+                self.status_message = Some(format!("fill_density set to {density:.3}"));
+            }
+            Command::Gradient { from, to, vertical } => {
+                self.game.randomize_gradient(from, to, vertical, None);
+                self.title = Some("gradient".to_string());
+                self.loaded = self.game.snapshot();
+            }
+            Command::Scene(path) => {
+                let errors = match Scene::from_file(&path) {
+                    Ok(scene) => {
+                        self.title = path
+                            .file_stem()
+                            .map(|stem| stem.to_string_lossy().into_owned());
+                        self.game.load_scene(&scene)
+                    }
+                    Err(e) => vec![e],
+                };
+                self.loaded = self.game.snapshot();
+                if !errors.is_empty() {
+                    self.status_message = Some(errors.join("; "));
+                }
+            }
+            Command::Open { name: None, .. } => {
+                let names: Vec<_> = patterns::list_bundled().collect();
+                self.status_message = Some(format!("bundled patterns: {}", names.join(", ")));
+            }
+            Command::Open {
+                name: Some(name),
+                transform,
+            } => match patterns::load_bundled(&name) {
+                Ok(cells) => {
+                    let cells = transform.apply(&cells);
+                    if self.game.load_cells(&cells) < cells.len() {
+                        self.status_message = Some(format!("{name} clipped to the grid"));
+                    }
+                    self.title = Some(name.to_lowercase());
+                    self.loaded = self.game.snapshot();
+                }
+                Err(e) => self.status_message = Some(e),
+            },
+            Command::Resize(width, height) => {
+                self.manual_size = true;
+                self.resize_grid(width, height);
+            }
+            Command::AutoSize => {
+                self.manual_size = false;
+                self.size_locked = false;
+                self.fit_grid_to_current_terminal();
+            }
+            Command::Fit(margin) => self.fit_to_pattern(margin),
+            Command::Rule(rule) => self.game.rule = rule,
+            Command::Topology(topology) => self.game.grid.set_topology(topology),
+            Command::Set(name, value) => {
+                let result = match name.as_str() {
+                    "min_interval" | "max_interval" | "interval_step" => {
+                        self.set_interval_bound(&name, &value)
+                    }
+                    "wrap_x" => value
+                        .parse()
+                        .map(|wrap_x| self.game.grid.set_wrap(wrap_x, self.game.grid.wrap_y()))
+                        .map_err(|_| format!("invalid value: {value}")),
+                    "wrap_y" => value
+                        .parse()
+                        .map(|wrap_y| self.game.grid.set_wrap(self.game.grid.wrap_x(), wrap_y))
+                        .map_err(|_| format!("invalid value: {value}")),
+                    "reversible" => value
+                        .parse()
+                        .map(|reversible| self.game.set_reversible(reversible))
+                        .map_err(|_| format!("invalid value: {value}")),
+                    "walls_alive" => value
+                        .parse()
+                        .map(|walls_alive| self.game.grid.set_walls_alive(walls_alive))
+                        .map_err(|_| format!("invalid value: {value}")),
+                    "birth_log" => value
+                        .parse()
+                        .map(|birth_log| self.game.grid.set_birth_log(birth_log))
+                        .map_err(|_| format!("invalid value: {value}")),
+                    _ => self.settings.set(&name, &value),
+                };
+                if let Err(message) = result {
+                    self.status_message = Some(message);
+                }
+            }
+            Command::Randomize { count, seed } => {
+                self.game.randomize_count(count, seed);
+                self.title = None;
+                self.loaded = self.game.snapshot();
+            }
+            Command::RandomizeSelection => match self.active_selection() {
+                Some((top, left, height, width)) => {
+                    let density = self.settings.fill_density;
+                    self.game
+                        .randomize_region(top, left, height, width, density);
+                }
+                None => self.randomize(),
+            },
+            Command::KeepSelection => match self.active_selection() {
+                Some((top, left, height, width)) => {
+                    self.game.clear_outside(top, left, height, width)
+                }
+                None => self.status_message = Some("nothing selected".to_string()),
+            },
+            Command::Step => match self.active_selection() {
+                Some((top, left, height, width)) => self.game.step_region(top, left, height, width),
+                None => self.game.step(),
+            },
+            Command::Record if self.is_recording => {
+                self.is_recording = false;
+                let length = self.recording.as_ref().map_or(0, Recording::len);
+                self.status_message = Some(format!("recorded {length} generations"));
+            }
+            Command::Record if self.game.is_reversible() => {
+                self.status_message = Some("can't record in reversible mode".to_string());
+            }
+            Command::Record => {
+                self.recording = Some(Recording::new(&self.game));
+                self.is_recording = true;
+                self.status_message = Some("recording (:record again to stop)".to_string());
+            }
+            Command::Replay => self.start_replay(),
+            Command::Perturb { count, seed } => self.game.perturb(count, seed),
+            Command::Keep {
+                top,
+                left,
+                height,
+                width,
+            } => self.game.clear_outside(top, left, height, width),
+            Command::Export(path) => {
+                self.status_message = Some(match self.game.history.save_csv(&path) {
+                    Ok(()) => format!(
+                        "exported {} generations to {}",
+                        self.game.history.len(),
+                        path.display()
+                    ),
+                    Err(e) => format!("failed to write {}: {e}", path.display()),
+                });
+            }
+        }
+    }
+
+    /// Tells the event thread the current tick interval, which is zero in turbo mode.
+    fn sync_tick_interval(&mut self) {
+        let interval = if self.turbo {
+            Duration::ZERO
+        } else {
+            self.game.tick_interval
+        };
+        self.events.set_tick_interval(interval);
+    }
+
+    /// Pauses or resumes the simulation, keeping the event thread in sync.
+    fn set_paused(&mut self, paused: bool) {
+        if self.game.is_paused() != paused {
+            self.game.toggle_pause();
+        }
+        if paused {
+            self.events.pause();
+        } else {
+            self.editing = false;
+            self.events.resume();
+        }
+    }
+
+    /// Runs the simulation for a number of generations from the current one, then pauses.
+    ///
+    /// Replaces any run target.
+    fn run_for(&mut self, generations: u64) {
+        self.game.run_target = None;
+        self.game.pause_after = Some(generations);
+        self.set_paused(false);
+    }
+
+    /// Pauses a running simulation before a hand edit if `pause_on_edit` is set, so the next
+    /// generation doesn't overwrite the edit.
+    fn pause_for_edit(&mut self) {
+        if self.settings.pause_on_edit && !self.game.is_paused() {
+            self.set_paused(true);
+            self.editing = true;
+        }
+    }
+
+    /// Processes mouse input.
+    ///
+    /// Scrolling up zooms in and scrolling down zooms out between rendering densities. Dragging
+    /// draws (left) or erases (right) cells, unless inspecting, where pointing at a cell shows
+    /// its details instead, or in select mode, where dragging (left) selects a rectangle.
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) {
+        let cell = self.cell_under(mouse_event.column, mouse_event.row);
+        match mouse_event.kind {
+            MouseEventKind::ScrollUp => self.render_mode = self.render_mode.zoom_in(),
+            MouseEventKind::ScrollDown => self.render_mode = self.render_mode.zoom_out(),
+            MouseEventKind::Down(MouseButton::Left) if self.select_mode => {
+                if let Some((row, col)) = cell {
+                    self.select_anchor = Some((row, col));
+                    self.select_to(row, col);
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.select_mode => {
+                if let Some((row, col)) = cell {
+                    self.select_to(row, col);
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.select_mode => self.select_anchor = None,
+            _ if self.select_mode => {}
+            MouseEventKind::Moved | MouseEventKind::Down(_) | MouseEventKind::Drag(_)
+                if self.inspect =>
+            {
+                self.inspect_at(mouse_event.column, mouse_event.row);
+            }
+            MouseEventKind::Down(MouseButton::Left) | MouseEventKind::Drag(MouseButton::Left) => {
+                self.paint_at(mouse_event.column, mouse_event.row, CellState::Alive);
+            }
+            MouseEventKind::Down(MouseButton::Right) | MouseEventKind::Drag(MouseButton::Right) => {
+                self.paint_at(mouse_event.column, mouse_event.row, CellState::Dead);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the (row, col) of the cell under a screen position, if any.
+    ///
+    /// In the denser render modes this is the top-left cell drawn by the character there.
+    fn cell_under(&self, x: u16, y: u16) -> Option<(usize, usize)> {
+        let area = self.grid_area.get()?;
+        if !area.contains((x, y).into()) {
+            return None;
+        }
+
+        Some(self.render_mode.cell_at(
+            (x - area.x) as usize,
+            (y - area.y) as usize,
+            self.cell_width,
+        ))
+    }
+
+    /// Shows the coordinates, state, and live neighbor count of the cell under a screen
+    /// position in the status bar.
+    fn inspect_at(&mut self, x: u16, y: u16) {
+        let Some((row, col)) = self.cell_under(x, y) else {
+            return;
+        };
+        let Some(state) = self.game.grid.get(row, col) else {
+            return;
+        };
+
+        let state = if state.is_alive() {
+            let age = self.game.grid.age(row, col);
+            match self.game.grid.born_at(row, col) {
+                Some(born) => format!("alive age={age} born={born}"),
+                None => format!("alive age={age}"),
+            }
+        } else {
+            "dead".to_string()
+        };
+        let neighbors = self.game.grid.count_neighbors(row, col);
+        self.status_message = Some(format!("({row},{col}) {state} neighbors={neighbors}"));
+    }
+
+    /// Paints with the brush centered on the cell under a screen position, if any.
+    ///
+    /// In wall mode, painting a live cell adds walls and painting a dead one removes them.
+    fn paint_at(&mut self, x: u16, y: u16, state: CellState) {
+        let Some((row, col)) = self.cell_under(x, y) else {
+            return;
+        };
+        self.pause_for_edit();
+        if self.wall_mode {
+            self.game.grid.paint_walls(
+                row,
+                col,
+                self.brush_radius,
+                self.settings.brush_shape,
+                state.is_alive(),
+            );
+            return;
+        }
+        self.game.grid.paint(
+            row,
+            col,
+            self.brush_radius,
+            self.settings.brush_shape,
+            state,
+        );
+    }
+
+    /// Returns a warning if the population exceeds the alert thresholds in the settings.
+    pub fn population_alert(&self) -> Option<&'static str> {
+        let grid = &self.game.grid;
+        let total_cells = grid.width() * grid.height();
+
+        if grid.live_count() as f32 > total_cells as f32 * self.settings.alert_density {
+            Some("population dense")
+        } else if self.game.last_step.net_growth() > self.settings.alert_growth as isize {
+            Some("population surging")
+        } else {
+            None
+        }
+    }
+
+    /// Saves the current board state into a quick-save slot.
+    fn save_slot(&mut self, slot: usize) {
+        self.slots[slot] = Some(self.game.snapshot());
+    }
+
+    /// Restores the board state from a quick-save slot, if one was saved.
+    fn load_slot(&mut self, slot: usize) {
+        if let Some(snapshot) = &self.slots[slot] {
+            self.game.restore(snapshot);
+        }
+    }
+
+    /// Signals the application to terminate, autosaving the board first if enabled.
+    ///
+    /// A failed autosave is recorded in `autosave_error` rather than keeping the app open.
     fn quit(&mut self) {
+        if self.settings.autosave_on_quit
+            && let Some(path) = &self.autosave_path
+            && let Err(e) = autosave::save(&self.game, path)
+        {
+            self.autosave_error = Some(format!("failed to autosave to {}: {e}", path.display()));
+        }
         self.should_quit = true;
     }
 }
 
 impl Default for App {
     fn default() -> Self {
-        let terminal_size = crossterm::terminal::size().unwrap();
-        let grid_size = calculate_grid_size(terminal_size);
+        AppBuilder::default().build()
+    }
+}
 
-        let settings = AppSettings::default();
+/// Builder for an [`App`] with explicit configuration.
+///
+/// Building does not touch the terminal unless the grid size is left unset, in which case the
+/// grid is sized to fit the current terminal, or an 80×24 terminal if there is none.
+#[derive(Debug, Clone, Default)]
+pub struct AppBuilder {
+    /// Grid dimensions as (width, height), or `None` to fit the terminal.
+    grid_size: Option<(usize, usize)>,
+    /// Name and live cells of a pattern to start with instead of a randomized grid.
+    pattern: Option<(String, Vec<(usize, usize)>)>,
+    /// Whether the pattern's cells are placed at their own coordinates instead of centered.
+    in_place: bool,
+    /// Birth and survival rule.
+    rule: Rule,
+    /// Time between simulation steps, or `None` for the default.
+    tick_interval: Option<Duration>,
+    /// Whether to start paused.
+    paused: bool,
+    /// Whether to start paused on an empty grid instead of a randomized one.
+    blank: bool,
+    /// Whether to size the grid to fit the starting pattern.
+    fit: bool,
+    /// Application settings.
+    settings: AppSettings,
+}
 
-        let mut game = Game::new(grid_size);
-        game.randomize(settings.fill_density);
+impl AppBuilder {
+    /// Sets the grid dimensions instead of fitting them to the terminal.
+    pub fn grid_size(mut self, width: usize, height: usize) -> Self {
+        self.grid_size = Some((width, height));
+        self
+    }
 
-        let events = EventHandler::new(game.tick_interval, game.is_paused());
+    /// Starts with a pattern centered on an empty grid instead of a randomized grid.
+    pub fn pattern(mut self, pattern: Pattern) -> Self {
+        self.pattern = Some((pattern.name.to_string(), pattern.cells.to_vec()));
+        self
+    }
 
-        Self {
+    /// Starts with live cells, given as (row, col) offsets, centered on an empty grid instead of
+    /// a randomized grid, titled with `name`.
+    pub fn cells(mut self, name: impl Into<String>, cells: Vec<(usize, usize)>) -> Self {
+        self.pattern = Some((name.into(), cells));
+        self
+    }
+
+    /// Starts from an autosaved board, with its cells where they were on a grid of the saved
+    /// size, under the saved rule.
+    pub fn resume(mut self, saved: Autosave) -> Self {
+        if let Some((width, height)) = saved.grid_size {
+            self.grid_size = Some((width, height));
+        }
+        self.rule = saved.rule;
+        self.pattern = Some(("resumed".to_string(), saved.cells));
+        self.in_place = true;
+        self
+    }
+
+    /// Sets the birth and survival rule.
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Sets the time between simulation steps.
+    pub fn tick_interval(mut self, tick_interval: Duration) -> Self {
+        self.tick_interval = Some(tick_interval);
+        self
+    }
+
+    /// Sets whether the simulation starts paused.
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Sets whether to start paused on an empty grid, for drawing, instead of a randomized one.
+    ///
+    /// Has no effect on the grid if a pattern is set.
+    pub fn blank(mut self, blank: bool) -> Self {
+        self.blank = blank;
+        self
+    }
+
+    /// Sets whether to size the grid to fit the starting pattern, plus the fit margin on every
+    /// side, instead of clipping it to the terminal.
+    ///
+    /// Has no effect unless a pattern is set. Takes precedence over the grid size.
+    pub fn fit(mut self, fit: bool) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    /// Sets the density of alive cells when randomizing (0.0 to 1.0).
+    pub fn fill_density(mut self, fill_density: f32) -> Self {
+        self.settings.fill_density = fill_density;
+        self
+    }
+
+    /// Sets what fitting the grid to a pattern larger than the maximum grid size does.
+    pub fn oversize(mut self, oversize: OversizePolicy) -> Self {
+        self.settings.oversize = oversize;
+        self
+    }
+
+    /// Builds the application.
+    ///
+    /// A pattern that doesn't fit the grid is clipped, with a warning in the status bar.
+    pub fn build(self) -> App {
+        let fit_size = self
+            .pattern
+            .as_ref()
+            .filter(|_| self.fit)
+            .map(|(_, cells)| {
+                let (height, width) = patterns::bounding_size(cells);
+                let (width, height) = Game::fit_size(width, height, self.settings.fit_margin);
+                self.settings
+                    .oversize
+                    .grid_size(width.max(1), height.max(1))
+            });
+        let grid_size = fit_size.or(self.grid_size).unwrap_or_else(|| {
+            let terminal_size = crossterm::terminal::size().unwrap_or(FALLBACK_TERMINAL_SIZE);
+            calculate_grid_size(terminal_size, DEFAULT_CELL_WIDTH)
+        });
+
+        let mut game = Game::new(grid_size);
+        game.rule = self.rule;
+        if let Some(tick_interval) = self.tick_interval {
+            game.tick_interval = tick_interval;
+        }
+        if self.paused || self.blank {
+            game.toggle_pause();
+        }
+        let mut status_message = None;
+        match &self.pattern {
+            Some((_, cells)) => {
+                let placed = if self.in_place {
+                    game.clear();
+                    game.grid.stamp(cells, 0, 0)
+                } else {
+                    game.load_cells(cells)
+                };
+                if placed < cells.len() {
+                    let (height, width) = patterns::bounding_size(cells);
+                    let (grid_width, grid_height) = game.grid.dimensions();
+                    status_message = Some(if width > MAX_GRID_WIDTH || height > MAX_GRID_HEIGHT {
+                        format!(
+                            "{width}×{height} pattern exceeds the \
+                             {MAX_GRID_WIDTH}×{MAX_GRID_HEIGHT} maximum, clipped to the grid"
+                        )
+                    } else {
+                        format!(
+                            "{width}×{height} pattern clipped to the {grid_width}×{grid_height} grid"
+                        )
+                    });
+                } else if let Some((width, height)) = fit_size
+                    && (width > MAX_GRID_WIDTH || height > MAX_GRID_HEIGHT)
+                {
+                    status_message = Some(format!(
+                        "{width}×{height} grid exceeds the {MAX_GRID_WIDTH}×{MAX_GRID_HEIGHT} maximum"
+                    ));
+                }
+            }
+            None if self.blank => {}
+            None => game.randomize(self.settings.fill_density),
+        }
+        let loaded = game.snapshot();
+
+        App {
             game,
             should_quit: false,
-            events,
-            settings,
+            events: EventHandler::new(),
+            settings: self.settings,
+            show_ruler: false,
+            show_trails: false,
+            show_sparkle: false,
+            render_mode: RenderMode::default(),
+            cell_width: DEFAULT_CELL_WIDTH,
+            manual_size: fit_size.is_some() || self.grid_size.is_some(),
+            size_locked: false,
+            show_stats: false,
+            show_help: false,
+            turbo: false,
+            rate_limited: false,
+            last_advance: None,
+            brush_radius: 0,
+            wall_mode: false,
+            inspect: false,
+            editing: false,
+            selection: None,
+            select_mode: false,
+            select_anchor: None,
+            select_cursor: (0, 0),
+            move_mode: false,
+            slots: Default::default(),
+            loaded,
+            title: self.pattern.map(|(name, _)| name),
+            command_input: None,
+            status_message,
+            copied: None,
+            jump: None,
+            stale_jump_notices: 0,
+            recording: None,
+            is_recording: false,
+            replay: None,
+            grid_area: Cell::default(),
+            csv_log: None,
+            autosave_path: None,
+            autosave_error: None,
+            #[cfg(feature = "log")]
+            metrics_log: None,
+            #[cfg(feature = "stream")]
+            stream: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU64;
+
+    use super::*;
+
+    /// Builds an app on a fixed-size random board, without touching the terminal.
+    fn test_app() -> App {
+        App::builder().grid_size(32, 16).build()
+    }
+
+    /// Queues a tick as the event thread would and handles it.
+    fn tick(app: &mut App) {
+        app.events.sender().send(Event::Tick).unwrap();
+        app.handle_events().unwrap();
+    }
+
+    /// Handles a key press with the given modifiers.
+    fn press(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+        app.handle_key_event(KeyEvent::new(code, modifiers))
+            .unwrap();
+    }
+
+    #[test]
+    fn esc_clears_the_selection_before_quitting() {
+        let mut app = test_app();
+        app.selection = Some((0, 0, 2, 2));
+        press(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(app.selection, None);
+        // A quit would be queued ahead of the tick
+        tick(&mut app);
+        assert!(!app.should_quit);
+
+        press(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+        app.handle_events().unwrap();
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn builds_without_a_grid_size_or_terminal() {
+        // Under `cargo test` there's usually no controlling terminal, in which case the size
+        // falls back to FALLBACK_TERMINAL_SIZE
+        let app = App::builder().build();
+        let terminal_size = crossterm::terminal::size().unwrap_or(FALLBACK_TERMINAL_SIZE);
+        let (width, height) = app.game.grid.dimensions();
+        assert_eq!(
+            (width, height),
+            calculate_grid_size(terminal_size, DEFAULT_CELL_WIDTH)
+        );
+        assert!((1..=MAX_GRID_WIDTH).contains(&width));
+        assert!((1..=MAX_GRID_HEIGHT).contains(&height));
+
+        // The fallback itself gives a usable grid
+        let (width, height) = calculate_grid_size(FALLBACK_TERMINAL_SIZE, DEFAULT_CELL_WIDTH);
+        assert_eq!((width, height), (38, 17));
+    }
+
+    #[test]
+    fn oversized_fit_margins_are_rejected() {
+        assert_eq!(Command::parse("fit 3"), Ok(Command::Fit(Some(3))));
+        assert!(Command::parse("fit 100000").is_err());
+        assert!(Command::parse(&format!("fit {}", usize::MAX)).is_err());
+
+        let mut app = test_app();
+        assert!(app.settings.set("fit_margin", "100000").is_err());
+        assert_eq!(app.settings.fit_margin, 2);
+    }
+
+    /// Registers a step hook on the app's game that counts the steps it sees.
+    fn count_steps(app: &mut App) -> Arc<AtomicU64> {
+        let steps = Arc::new(AtomicU64::new(0));
+        let counter = Arc::clone(&steps);
+        app.game.set_on_step(Box::new(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }));
+        steps
+    }
+
+    #[test]
+    fn step_hook_runs_through_a_jump_and_survives_it() {
+        let mut app = test_app();
+        let steps = count_steps(&mut app);
+        let target = app.game.generation + 20;
+        app.execute_command(Command::Goto(target));
+        while app.jump.is_some() {
+            app.handle_events().unwrap();
+        }
+        assert_eq!(app.game.generation, target);
+        assert_eq!(steps.load(Ordering::Relaxed), 20);
+
+        app.game.step();
+        assert_eq!(steps.load(Ordering::Relaxed), 21);
+    }
+
+    #[test]
+    fn step_hook_survives_a_cancelled_jump() {
+        let mut app = test_app();
+        let steps = count_steps(&mut app);
+        let generation = app.game.generation;
+        app.execute_command(Command::Goto(u64::MAX));
+        app.cancel_jump();
+        assert_eq!(app.game.generation, generation);
+
+        let before = steps.load(Ordering::Relaxed);
+        app.game.step();
+        assert_eq!(steps.load(Ordering::Relaxed), before + 1);
+
+        // The cancelled jump's notice doesn't end the next one early
+        let target = app.game.generation + 10;
+        app.execute_command(Command::Goto(target));
+        while app.jump.is_some() {
+            app.handle_events().unwrap();
+        }
+        assert_eq!(app.game.generation, target);
+        assert_eq!(steps.load(Ordering::Relaxed), before + 11);
+    }
+
+    #[test]
+    fn editing_the_board_stops_the_recording() {
+        let mut app = test_app();
+        app.execute_command(Command::Record);
+        app.set_paused(false);
+        for _ in 0..5 {
+            tick(&mut app);
+        }
+        assert!(app.is_recording);
+
+        let state = app.game.grid.get(0, 0).unwrap();
+        app.game
+            .grid
+            .set(0, 0, CellState::from_alive(!state.is_alive()));
+        tick(&mut app);
+        assert!(!app.is_recording);
+        assert_eq!(app.recording.as_ref().map(Recording::len), Some(5));
+        assert!(
+            app.status_message
+                .as_ref()
+                .unwrap()
+                .contains("board was changed")
+        );
+    }
+
+    #[test]
+    fn fit_follows_the_oversize_policy() {
+        for (policy, size, population, warning) in [
+            (OversizePolicy::Clip, (MAX_GRID_WIDTH, 5), 1, "clipped"),
+            (OversizePolicy::Allow, (265, 5), 2, "exceeds"),
+        ] {
+            let mut app = test_app();
+            app.settings.oversize = policy;
+            app.execute_command(Command::Resize(300, 20));
+            app.game.clear();
+            app.game.grid.set(5, 10, CellState::Alive);
+            app.game.grid.set(5, 270, CellState::Alive);
+            app.execute_command(Command::Fit(None));
+            assert_eq!(app.game.grid.dimensions(), size, "{policy:?}");
+            assert_eq!(app.game.grid.live_count(), population, "{policy:?}");
+            let status = app.status_message.clone().unwrap();
+            assert!(status.contains(warning), "{policy:?}: {status}");
+        }
+    }
+
+    #[test]
+    fn run_for_stops_at_exactly_the_requested_generation() {
+        for turbo in [false, true] {
+            let mut app = test_app();
+            app.turbo = turbo;
+            let start = app.game.generation;
+            app.run_for(5);
+            for _ in 0..20 {
+                tick(&mut app);
+            }
+            assert_eq!(app.game.generation, start + 5, "turbo: {turbo}");
+            assert!(app.game.is_paused());
+            assert_eq!(app.game.pause_after, None);
+        }
+    }
+
+    #[test]
+    fn run_to_stops_on_the_target_generation() {
+        for turbo in [false, true] {
+            let mut app = test_app();
+            app.turbo = turbo;
+            let target = app.game.generation + 7;
+            app.execute_command(Command::RunTo(target));
+            for _ in 0..20 {
+                tick(&mut app);
+            }
+            assert_eq!(app.game.generation, target, "turbo: {turbo}");
+            assert!(app.game.is_paused());
+            assert_eq!(app.game.run_target, None);
+        }
+    }
+
+    #[test]
+    fn speed_preset_keys_respect_configured_interval_bounds() {
+        let mut app = test_app();
+        app.execute_command(Command::Set("min_interval".into(), "80".into()));
+        app.execute_command(Command::Set("max_interval".into(), "300".into()));
+
+        press(&mut app, KeyCode::F(5), KeyModifiers::NONE);
+        assert_eq!(app.game.tick_interval, Duration::from_millis(80));
+        assert_eq!(app.game.speed_preset(), Some("fastest"));
+        press(&mut app, KeyCode::F(1), KeyModifiers::NONE);
+        assert_eq!(app.game.tick_interval, Duration::from_millis(300));
+        press(&mut app, KeyCode::F(2), KeyModifiers::NONE);
+        assert_eq!(app.game.tick_interval, Duration::from_millis(300));
+    }
+
+    #[test]
+    fn ctrl_v_pastes_the_last_copied_pattern() {
+        let mut app = test_app();
+        let before = app.game.grid.clone();
+        press(&mut app, KeyCode::Char('v'), KeyModifiers::CONTROL);
+        assert!(app.game.grid == before);
+        assert!(
+            app.status_message
+                .as_ref()
+                .unwrap()
+                .starts_with("nothing copied")
+        );
+
+        let glider = patterns::find("glider").unwrap().cells;
+        app.copied = Some(patterns::to_rle(glider, &app.game.rule));
+        app.game.grid.clear();
+        press(&mut app, KeyCode::Char('v'), KeyModifiers::CONTROL);
+        assert_eq!(app.game.grid.live_count(), glider.len());
+        assert_eq!(app.status_message.as_deref(), Some("pasted 3×3 pattern"));
+    }
+
+    #[test]
+    fn each_extinction_policy_applies_when_the_board_dies_out() {
+        for policy in [
+            ExtinctionPolicy::Continue,
+            ExtinctionPolicy::Pause,
+            ExtinctionPolicy::Reseed,
+            ExtinctionPolicy::Quit,
+        ] {
+            let mut app = test_app();
+            app.settings.on_extinction = policy;
+            app.game.grid.clear();
+            app.game.grid.set(4, 4, CellState::Alive);
+            app.set_paused(false);
+            tick(&mut app);
+
+            let generation = app.game.generation;
+            match policy {
+                ExtinctionPolicy::Continue => {
+                    assert!(app.game.grid.is_empty());
+                    assert!(!app.game.is_paused());
+                    assert!(!app.should_quit);
+                    tick(&mut app);
+                    assert_eq!(app.game.generation, generation + 1);
+                }
+                ExtinctionPolicy::Pause => {
+                    assert!(app.game.grid.is_empty());
+                    assert!(app.game.is_paused());
+                    assert!(!app.should_quit);
+                    assert_eq!(
+                        app.status_message,
+                        Some(format!("died out at generation {generation}"))
+                    );
+                    tick(&mut app);
+                    assert_eq!(app.game.generation, generation);
+                }
+                ExtinctionPolicy::Reseed => {
+                    assert!(!app.game.grid.is_empty());
+                    assert!(!app.game.is_paused());
+                    assert!(!app.should_quit);
+                }
+                ExtinctionPolicy::Quit => {
+                    assert!(app.game.grid.is_empty());
+                    assert!(app.should_quit);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ctrl_c_quits_but_c_clears() {
+        let mut app = test_app();
+        assert!(!app.game.grid.is_empty());
+        press(&mut app, KeyCode::Char('c'), KeyModifiers::NONE);
+        app.handle_events().unwrap();
+        assert!(app.game.grid.is_empty());
+        assert!(!app.should_quit);
+
+        press(&mut app, KeyCode::Char('c'), KeyModifiers::CONTROL);
+        app.handle_events().unwrap();
+        assert!(app.should_quit);
+
+        // Even while typing a command
+        let mut app = test_app();
+        press(&mut app, KeyCode::Char(':'), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('c'), KeyModifiers::CONTROL);
+        app.handle_events().unwrap();
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn resetting_the_generation_leaves_the_grid_unchanged() {
+        let mut app = test_app();
+        app.set_paused(false);
+        for _ in 0..5 {
+            tick(&mut app);
+        }
+        assert_eq!(app.game.generation, 5);
+        assert!(!app.game.history.is_empty());
+        let (grid, population) = (app.game.grid.clone(), app.game.grid.live_count());
+
+        press(&mut app, KeyCode::Char('0'), KeyModifiers::NONE);
+        assert_eq!(app.game.generation, 0);
+        assert!(app.game.history.is_empty());
+        assert!(app.game.grid == grid);
+        assert_eq!(app.game.grid.live_count(), population);
+        assert_eq!(app.status_message.as_deref(), Some("generation reset to 0"));
+    }
+}
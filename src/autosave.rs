@@ -0,0 +1,98 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{game::Game, patterns, rule::Rule};
+
+/// File name of the autosave within the state directory.
+const FILE_NAME: &str = "last.rle";
+
+/// Prefix of the comment line recording the grid size, e.g. `#C grid = 80x24`.
+const GRID_COMMENT: &str = "#C grid = ";
+
+/// A board saved on quit, to pick up where the session left off.
+///
+/// Autosaves are ordinary RLE files with the live cells at their positions on the grid, the rule
+/// in the header, and the grid size in a `#C grid = <width>x<height>` comment, so any RLE reader
+/// can open them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Autosave {
+    /// Grid dimensions as (width, height), or `None` if the file doesn't record them.
+    pub grid_size: Option<(usize, usize)>,
+    /// Rule from the RLE header, or the default if there is none.
+    pub rule: Rule,
+    /// Live cells as (row, col).
+    pub cells: Vec<(usize, usize)>,
+}
+
+/// Returns where the autosave is kept: `$XDG_STATE_HOME/ratgol/last.rle`, falling back to
+/// `~/.local/state/ratgol/last.rle`.
+///
+/// Returns `None` if neither variable is set.
+pub fn default_path() -> Option<PathBuf> {
+    let state_home = env::var_os("XDG_STATE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local/state")))?;
+    Some(state_home.join("ratgol").join(FILE_NAME))
+}
+
+/// Writes the game's board, rule, and grid size to `path`, creating its directory if needed.
+pub fn save(game: &Game, path: &Path) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let cells: Vec<_> = game.grid.iter_alive_cells().collect();
+    let (width, height) = game.grid.dimensions();
+    let text = format!(
+        "#C Saved by ratgol on quit; delete this file to discard it.\n\
+         {GRID_COMMENT}{width}x{height}\n{}",
+        patterns::to_rle(&cells, &game.rule)
+    );
+    fs::write(path, text)
+}
+
+/// Reads an autosave written by [`save`].
+pub fn load(path: &Path) -> Result<Autosave, String> {
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let invalid = |e: String| format!("invalid autosave {}: {e}", path.display());
+
+    let grid_size = text
+        .lines()
+        .find_map(|line| line.trim().strip_prefix(GRID_COMMENT))
+        .map(|size| {
+            size.split_once('x')
+                .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)))
+                .filter(|&(width, height)| width > 0 && height > 0)
+                .ok_or_else(|| invalid(format!("bad grid size: {size}")))
+        })
+        .transpose()?;
+    let rule = text
+        .lines()
+        .find(|line| line.trim_start().starts_with('x'))
+        .and_then(|header| header.split_once("rule"))
+        .and_then(|(_, rule)| rule.trim_start().strip_prefix('='))
+        .map(|rule| Rule::parse(rule).map_err(|e| invalid(e.to_string())))
+        .transpose()?
+        .unwrap_or_default();
+
+    // An empty board is saved with a body of just `!`, which the RLE reader rejects
+    let is_empty = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('x'))
+        .eq(["!"]);
+    let cells = if is_empty {
+        Vec::new()
+    } else {
+        patterns::from_rle(&text).map_err(invalid)?
+    };
+
+    Ok(Autosave {
+        grid_size,
+        rule,
+        cells,
+    })
+}
@@ -0,0 +1,139 @@
+use std::path::PathBuf;
+
+use ratgol::{app::OversizePolicy, patterns::Transform};
+
+/// Usage text shown for `--help` and invalid arguments.
+pub const USAGE: &str = "\
+usage: ratgol [options] [pattern]
+       ratgol diff <a> <b>
+
+arguments:
+  [pattern]          built-in or bundled pattern name, pattern file (RLE or plaintext), or - for stdin
+  diff <a> <b>       compare two patterns without starting the TUI, exiting with 1 if they differ
+
+options:
+  --paused           start with the simulation paused
+  --blank            start paused on an empty grid instead of a random one
+  --fit              size the grid to fit the pattern instead of the terminal
+  --oversize <mode>  with --fit, clip (default) or allow patterns beyond the largest grid
+  --transform <t>    rotate (r90, r180, r270) or flip (fh, fv) the pattern, combined with +
+  --resume           start from the board autosaved when ratgol last quit
+  --no-altscreen     draw in the normal screen, leaving the last frame in the scrollback
+  --csv <path>       append each generation's population data to a CSV file
+  --log <path>       write throughput metrics to <path> (requires the `log` feature)
+  --log-every <n>    generations between metrics records (default: 100)
+  --stream <addr>    stream each generation to TCP clients on <addr>, or run without the TUI
+                     and write them to stdout with - (requires the `stream` feature)
+  -h, --help         print this help";
+
+/// Command-line arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Args {
+    /// Pattern to start with: a built-in name, a file path, or `-` for stdin.
+    pub pattern: Option<String>,
+    /// CSV file to append population data to.
+    pub csv: Option<PathBuf>,
+    /// File to write throughput metrics to.
+    pub log: Option<PathBuf>,
+    /// Generations between metrics records, or `None` for the default.
+    pub log_every: Option<u64>,
+    /// Address to stream generations to TCP clients on, or `-` for stdout.
+    pub stream: Option<String>,
+    /// Whether to start with the simulation paused.
+    pub paused: bool,
+    /// Whether to start paused on an empty grid instead of a randomized one.
+    pub blank: bool,
+    /// Whether to size the grid to fit the pattern instead of the terminal.
+    pub fit: bool,
+    /// What fitting the grid to a pattern larger than the maximum grid size does.
+    pub oversize: OversizePolicy,
+    /// Rotation and reflection to apply to the pattern.
+    pub transform: Transform,
+    /// Whether to start from the autosaved board instead of a pattern.
+    pub resume: bool,
+    /// Whether to draw in the normal screen instead of the alternate screen.
+    pub no_altscreen: bool,
+    /// Whether to print usage and exit.
+    pub help: bool,
+    /// Two patterns to compare instead of starting the TUI.
+    pub diff: Option<(String, String)>,
+}
+
+impl Args {
+    /// Parses arguments, excluding the program name.
+    ///
+    /// Returns a message describing the problem if the arguments are invalid.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self {
+            pattern: None,
+            csv: None,
+            log: None,
+            log_every: None,
+            stream: None,
+            paused: false,
+            blank: false,
+            fit: false,
+            oversize: OversizePolicy::default(),
+            transform: Transform::default(),
+            resume: false,
+            no_altscreen: false,
+            help: false,
+            diff: None,
+        };
+
+        let mut args = args.into_iter().peekable();
+        if args.next_if_eq("diff").is_some() {
+            return match (args.next(), args.next(), args.next()) {
+                (Some(a), Some(b), None) => {
+                    parsed.diff = Some((a, b));
+                    Ok(parsed)
+                }
+                _ => Err("usage: ratgol diff <a> <b>".to_string()),
+            };
+        }
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {arg}"))
+            };
+            match arg.as_str() {
+                "--csv" => parsed.csv = Some(PathBuf::from(value()?)),
+                "--log" => parsed.log = Some(PathBuf::from(value()?)),
+                "--log-every" => {
+                    let value = value()?;
+                    match value.parse() {
+                        Ok(0) | Err(_) => return Err(format!("invalid value for {arg}: {value}")),
+                        Ok(n) => parsed.log_every = Some(n),
+                    }
+                }
+                "--stream" => parsed.stream = Some(value()?),
+                "--oversize" => {
+                    let value = value()?;
+                    parsed.oversize = value.parse()?;
+                }
+                "--transform" => {
+                    let value = value()?;
+                    parsed.transform = value.parse()?;
+                }
+                "--paused" => parsed.paused = true,
+                "--blank" => parsed.blank = true,
+                "--fit" => parsed.fit = true,
+                "--resume" => parsed.resume = true,
+                "--no-altscreen" => parsed.no_altscreen = true,
+                "-h" | "--help" => parsed.help = true,
+                _ if arg.starts_with('-') && arg != "-" => {
+                    return Err(format!("unknown option: {arg}"));
+                }
+                _ if parsed.pattern.is_none() => parsed.pattern = Some(arg),
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+        if parsed.resume && parsed.pattern.is_some() {
+            return Err("--resume can't be combined with a pattern".to_string());
+        }
+        if parsed.transform != Transform::default() && parsed.pattern.is_none() {
+            return Err("--transform needs a pattern".to_string());
+        }
+        Ok(parsed)
+    }
+}
@@ -0,0 +1,94 @@
+use std::io::{self, Write};
+
+/// Alphabet for standard base64 encoding.
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Copies text to the system clipboard.
+///
+/// With the `clipboard` feature the clipboard is written directly, falling back to the terminal
+/// if that fails (e.g. over SSH, with no display to reach).
+pub fn copy(text: &str) -> io::Result<()> {
+    #[cfg(feature = "clipboard")]
+    if system::copy(text) {
+        return Ok(());
+    }
+    copy_osc52(text)
+}
+
+/// Returns the text on the system clipboard, or `None` if it can't be read or holds no text.
+///
+/// Only the `clipboard` feature can read the clipboard; without it this always returns `None`.
+#[cfg(feature = "clipboard")]
+pub fn paste() -> Option<String> {
+    system::paste()
+}
+
+/// Returns the text on the system clipboard, which can't be read without the `clipboard`
+/// feature.
+#[cfg(not(feature = "clipboard"))]
+pub fn paste() -> Option<String> {
+    None
+}
+
+/// Copies text to the system clipboard through the terminal with an OSC 52 escape sequence.
+///
+/// The terminal has to support OSC 52 (most modern terminals and tmux with `set-clipboard on`
+/// do); others silently ignore the sequence, so success only means it was written.
+fn copy_osc52(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", base64(text.as_bytes()))?;
+    stdout.flush()
+}
+
+/// Direct access to the system clipboard.
+#[cfg(feature = "clipboard")]
+mod system {
+    use std::sync::Mutex;
+
+    use arboard::Clipboard;
+
+    /// Clipboard connection, opened on first use and kept open: on X11 and Wayland, text copied
+    /// is only served while the connection that copied it stays open.
+    static CLIPBOARD: Mutex<Option<Clipboard>> = Mutex::new(None);
+
+    /// Runs `f` on the clipboard connection, opening it if needed, or returns `None` if it can't
+    /// be opened.
+    fn with_clipboard<T>(f: impl FnOnce(&mut Clipboard) -> T) -> Option<T> {
+        let mut clipboard = CLIPBOARD.lock().unwrap_or_else(|e| e.into_inner());
+        if clipboard.is_none() {
+            *clipboard = Clipboard::new().ok();
+        }
+        clipboard.as_mut().map(f)
+    }
+
+    /// Writes text to the clipboard, returning whether it worked.
+    pub fn copy(text: &str) -> bool {
+        with_clipboard(|clipboard| clipboard.set_text(text).is_ok()).unwrap_or(false)
+    }
+
+    /// Reads text from the clipboard.
+    pub fn paste() -> Option<String> {
+        with_clipboard(|clipboard| clipboard.get_text().ok()).flatten()
+    }
+}
+
+/// Encodes bytes as padded base64.
+fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = u32::from(b[0]) << 16 | u32::from(b[1]) << 8 | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
@@ -0,0 +1,253 @@
+use std::{path::PathBuf, str::FromStr};
+
+use crate::{
+    condition::StopCondition,
+    game::Game,
+    grid::{DEFAULT_MAX_CELLS, Grid, Topology},
+    patterns::Transform,
+    rule::Rule,
+};
+
+/// A command entered at the `:` prompt.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Run until the given generation, then pause.
+    RunTo(u64),
+    /// Run for the given number of generations from the current one, then pause.
+    Run(u64),
+    /// Jump straight to the given generation in the background, without drawing in between.
+    Goto(u64),
+    /// Run in the background without drawing in between until a condition holds, then pause.
+    Until(StopCondition),
+    /// Fill the grid with a checkerboard.
+    Checker,
+    /// Fill the grid with stripes of the given thickness.
+    Stripes {
+        /// Thickness of each stripe in cells.
+        period: usize,
+        /// Whether stripes run top to bottom.
+        vertical: bool,
+    },
+    /// Load a scene file.
+    Scene(PathBuf),
+    /// Load a bundled pattern, or list them if no name is given.
+    Open {
+        /// Name of the bundled pattern.
+        name: Option<String>,
+        /// Rotation and reflection to apply to the pattern.
+        transform: Transform,
+    },
+    /// Resize the grid to a fixed (width, height), ignoring terminal resizes.
+    Resize(usize, usize),
+    /// Size the grid to fit the terminal again.
+    AutoSize,
+    /// Size the grid to fit its live cells, with an optional margin in cells.
+    Fit(Option<usize>),
+    /// Change the birth and survival rule.
+    Rule(Rule),
+    /// Change how the grid's edges connect.
+    Topology(Topology),
+    /// Change a setting by name.
+    Set(String, String),
+    /// Write the population history to a CSV file.
+    Export(PathBuf),
+    /// Kill every cell outside a rectangular region.
+    Keep {
+        /// Row of the region's top edge.
+        top: usize,
+        /// Column of the region's left edge.
+        left: usize,
+        /// Height of the region in cells.
+        height: usize,
+        /// Width of the region in cells.
+        width: usize,
+    },
+    /// Randomize the grid with an exact number of live cells, optionally with a seed for a
+    /// reproducible choice.
+    Randomize {
+        /// Number of live cells.
+        count: usize,
+        /// Seed for choosing the cells.
+        seed: Option<u64>,
+    },
+    /// Randomize the grid with a density that varies linearly across it.
+    Gradient {
+        /// Density on the top or left edge.
+        from: f32,
+        /// Density on the bottom or right edge.
+        to: f32,
+        /// Whether the density varies top to bottom.
+        vertical: bool,
+    },
+    /// Search for a fill density whose soups settle to about the given population, then
+    /// randomize with it.
+    FindDensity(usize),
+    /// Randomize the selection, or the whole grid without one, with the fill density.
+    RandomizeSelection,
+    /// Kill every cell outside the selection.
+    KeepSelection,
+    /// Advance one generation, only inside the selection if there is one.
+    Step,
+    /// Start recording the run, or stop if already recording.
+    Record,
+    /// Play back the last recording.
+    Replay,
+    /// Toggle random cells, optionally with a seed for a reproducible choice.
+    Perturb {
+        /// Number of cells to toggle.
+        count: usize,
+        /// Seed for choosing the cells.
+        seed: Option<u64>,
+    },
+}
+
+impl Command {
+    /// Parses a command line such as `runto 1000`.
+    ///
+    /// Returns a message describing the problem if the input is not a valid command.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut words = input.split_whitespace();
+        let name = words.next().ok_or("empty command")?;
+        let args: Vec<&str> = words.collect();
+
+        match (name, args.as_slice()) {
+            ("runto", [generation]) => Ok(Command::RunTo(parse_arg(generation)?)),
+            ("runto", _) => Err("usage: runto <generation>".to_string()),
+            ("run", [generations]) => match parse_arg(generations)? {
+                0 => Err("generations must be non-zero".to_string()),
+                generations => Ok(Command::Run(generations)),
+            },
+            ("run", _) => Err("usage: run <generations>".to_string()),
+            ("goto", [generation]) => Ok(Command::Goto(parse_arg(generation)?)),
+            ("goto", _) => Err("usage: goto <generation>".to_string()),
+            ("until", []) => Err("usage: until <condition>".to_string()),
+            ("until", condition) => condition.concat().parse().map(Command::Until),
+            ("checker", []) => Ok(Command::Checker),
+            ("stripes", [period]) => Ok(Command::Stripes {
+                period: parse_period(period)?,
+                vertical: false,
+            }),
+            ("stripes", [period, direction @ ("h" | "v")]) => Ok(Command::Stripes {
+                period: parse_period(period)?,
+                vertical: *direction == "v",
+            }),
+            ("checker", _) => Err("usage: checker".to_string()),
+            ("stripes", _) => Err("usage: stripes <period> [h|v]".to_string()),
+            ("scene", [path]) => Ok(Command::Scene(PathBuf::from(path))),
+            ("scene", _) => Err("usage: scene <path>".to_string()),
+            ("open", []) => Ok(Command::Open {
+                name: None,
+                transform: Transform::default(),
+            }),
+            ("open", [name]) => Ok(Command::Open {
+                name: Some(name.to_string()),
+                transform: Transform::default(),
+            }),
+            ("open", [name, transform]) => Ok(Command::Open {
+                name: Some(name.to_string()),
+                transform: transform.parse()?,
+            }),
+            ("open", _) => Err("usage: open [name] [transform]".to_string()),
+            ("resize", [width, height]) => match (parse_arg(width)?, parse_arg(height)?) {
+                (0, _) | (_, 0) => Err("dimensions must be non-zero".to_string()),
+                (width, height) => Grid::check_dimensions(width, height, DEFAULT_MAX_CELLS)
+                    .map(|_| Command::Resize(width, height))
+                    .map_err(|e| e.to_string()),
+            },
+            ("resize", _) => Err("usage: resize <width> <height>".to_string()),
+            ("autosize", []) => Ok(Command::AutoSize),
+            ("autosize", _) => Err("usage: autosize".to_string()),
+            ("fit", []) => Ok(Command::Fit(None)),
+            ("fit", [margin]) => Ok(Command::Fit(Some(parse_fit_margin(margin)?))),
+            ("fit", _) => Err("usage: fit [margin]".to_string()),
+            ("rule", [rule]) => Rule::parse(rule)
+                .map(Command::Rule)
+                .map_err(|e| format!("invalid rule: {e}")),
+            ("rule", _) => Err("usage: rule <B.../S...>".to_string()),
+            ("topology", [topology]) => topology.parse().map(Command::Topology),
+            ("topology", _) => Err("usage: topology <torus|cylinder|bounded>".to_string()),
+            ("set", [name, value]) => Ok(Command::Set(name.to_string(), value.to_string())),
+            ("set", _) => Err("usage: set <name> <value>".to_string()),
+            ("perturb", [count]) => Ok(Command::Perturb {
+                count: parse_arg(count)?,
+                seed: None,
+            }),
+            ("perturb", [count, seed]) => Ok(Command::Perturb {
+                count: parse_arg(count)?,
+                seed: Some(parse_arg(seed)?),
+            }),
+            ("randomize", []) => Ok(Command::RandomizeSelection),
+            ("randomize", [count]) => Ok(Command::Randomize {
+                count: parse_arg(count)?,
+                seed: None,
+            }),
+            ("randomize", [count, seed]) => Ok(Command::Randomize {
+                count: parse_arg(count)?,
+                seed: Some(parse_arg(seed)?),
+            }),
+            ("randomize", _) => Err("usage: randomize [<count> [seed]]".to_string()),
+            ("gradient", [from, to]) => Ok(Command::Gradient {
+                from: parse_density(from)?,
+                to: parse_density(to)?,
+                vertical: true,
+            }),
+            ("gradient", [from, to, direction @ ("h" | "v")]) => Ok(Command::Gradient {
+                from: parse_density(from)?,
+                to: parse_density(to)?,
+                vertical: *direction == "v",
+            }),
+            ("density", [population]) => Ok(Command::FindDensity(parse_arg(population)?)),
+            ("density", _) => Err("usage: density <population>".to_string()),
+            ("gradient", _) => Err("usage: gradient <from> <to> [h|v]".to_string()),
+            ("export", [path]) => Ok(Command::Export(PathBuf::from(path))),
+            ("export", _) => Err("usage: export <path>".to_string()),
+            ("perturb", _) => Err("usage: perturb <count> [seed]".to_string()),
+            ("keep", [top, left, height, width]) => Ok(Command::Keep {
+                top: parse_arg(top)?,
+                left: parse_arg(left)?,
+                height: parse_arg(height)?,
+                width: parse_arg(width)?,
+            }),
+            ("keep", []) => Ok(Command::KeepSelection),
+            ("keep", _) => Err("usage: keep [<top> <left> <height> <width>]".to_string()),
+            ("record", []) => Ok(Command::Record),
+            ("record", _) => Err("usage: record".to_string()),
+            ("replay", []) => Ok(Command::Replay),
+            ("replay", _) => Err("usage: replay".to_string()),
+            ("step", []) => Ok(Command::Step),
+            ("step", _) => Err("usage: step".to_string()),
+            _ => Err(format!("unknown command: {name}")),
+        }
+    }
+}
+
+/// Parses a stripe period, which must be non-zero.
+fn parse_period(arg: &str) -> Result<usize, String> {
+    match parse_arg(arg)? {
+        0 => Err("period must be non-zero".to_string()),
+        period => Ok(period),
+    }
+}
+
+/// Parses a fill density, which must be within 0.0 to 1.0.
+fn parse_density(arg: &str) -> Result<f32, String> {
+    let density = parse_arg(arg)?;
+    if !(0.0..=1.0).contains(&density) {
+        return Err("density must be within 0.0 to 1.0".to_string());
+    }
+    Ok(density)
+}
+
+/// Parses a single command argument.
+fn parse_arg<T: FromStr>(arg: &str) -> Result<T, String> {
+    arg.parse().map_err(|_| format!("invalid argument: {arg}"))
+}
+
+/// Parses a fit margin, rejecting margins that alone would make a fitted grid too large.
+pub(crate) fn parse_fit_margin(arg: &str) -> Result<usize, String> {
+    let margin = parse_arg(arg)?;
+    let (width, height) = Game::fit_size(1, 1, margin);
+    Grid::check_dimensions(width, height, DEFAULT_MAX_CELLS)
+        .map(|_| margin)
+        .map_err(|e| format!("margin too large: {e}"))
+}
@@ -0,0 +1,267 @@
+use std::{fmt, str::FromStr};
+
+use crate::{game::Game, grid::GridBackend};
+
+/// How a value is compared against a threshold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    /// Strictly less than.
+    Less,
+    /// Less than or equal to.
+    AtMost,
+    /// Exactly equal to.
+    Equal,
+    /// Greater than or equal to.
+    AtLeast,
+    /// Strictly greater than.
+    Greater,
+}
+
+impl Comparison {
+    /// Returns true if `value` compares to `threshold` this way.
+    pub fn holds(self, value: usize, threshold: usize) -> bool {
+        match self {
+            Comparison::Less => value < threshold,
+            Comparison::AtMost => value <= threshold,
+            Comparison::Equal => value == threshold,
+            Comparison::AtLeast => value >= threshold,
+            Comparison::Greater => value > threshold,
+        }
+    }
+
+    /// Returns the operator written for this comparison.
+    fn symbol(self) -> &'static str {
+        match self {
+            Comparison::Less => "<",
+            Comparison::AtMost => "<=",
+            Comparison::Equal => "=",
+            Comparison::AtLeast => ">=",
+            Comparison::Greater => ">",
+        }
+    }
+}
+
+/// Something to watch for while running, to stop once it happens.
+///
+/// Conditions are written as `pop>500` (any of `<`, `<=`, `=`, `>=`, `>`), `stable`,
+/// `extinct`, or `escape`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StopCondition {
+    /// The population compares to a threshold.
+    Population(Comparison, usize),
+    /// The board repeats itself, as a still life or oscillator, or has died out.
+    Stable,
+    /// Every cell is dead.
+    Extinct,
+    /// A live cell is outside the bounding box the live cells had at the start.
+    Escape,
+}
+
+impl StopCondition {
+    /// Returns true if the condition holds for the game.
+    ///
+    /// `start_bounds` is the game's [`Grid::live_bounds`](crate::grid::Grid::live_bounds)
+    /// when the run started, which only [`StopCondition::Escape`] looks at; anything alive
+    /// escapes an empty start.
+    pub fn is_met(&self, game: &Game, start_bounds: Option<(usize, usize, usize, usize)>) -> bool {
+        match *self {
+            StopCondition::Population(comparison, threshold) => {
+                comparison.holds(game.grid.live_count(), threshold)
+            }
+            StopCondition::Stable => game.grid.is_empty() || game.period().is_some(),
+            StopCondition::Extinct => game.grid.is_empty(),
+            StopCondition::Escape => {
+                let Some((top, left, height, width)) = start_bounds else {
+                    return !game.grid.is_empty();
+                };
+                game.grid.iter_alive_cells().any(|(row, col)| {
+                    row < top || row >= top + height || col < left || col >= left + width
+                })
+            }
+        }
+    }
+}
+
+impl FromStr for StopCondition {
+    type Err = String;
+
+    /// Parses a condition, ignoring whitespace so `pop > 500` reads the same as `pop>500`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: String = s.split_whitespace().collect();
+        match s.as_str() {
+            "stable" => return Ok(StopCondition::Stable),
+            "extinct" => return Ok(StopCondition::Extinct),
+            "escape" => return Ok(StopCondition::Escape),
+            _ => {}
+        }
+
+        let threshold = s
+            .strip_prefix("pop")
+            .ok_or_else(|| format!("unknown condition: {s}"))?;
+        // Two-character operators first, so `<=` isn't read as `<` followed by `=500`
+        let (comparison, value) = [
+            ("<=", Comparison::AtMost),
+            (">=", Comparison::AtLeast),
+            ("<", Comparison::Less),
+            (">", Comparison::Greater),
+            ("=", Comparison::Equal),
+        ]
+        .into_iter()
+        .find_map(|(symbol, comparison)| {
+            threshold
+                .strip_prefix(symbol)
+                .map(|value| (comparison, value))
+        })
+        .ok_or_else(|| format!("expected <, <=, =, >=, or > after pop: {s}"))?;
+        let value = value
+            .parse()
+            .map_err(|_| format!("invalid population: {value}"))?;
+        Ok(StopCondition::Population(comparison, value))
+    }
+}
+
+impl fmt::Display for StopCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopCondition::Population(comparison, threshold) => {
+                write!(f, "pop{}{threshold}", comparison.symbol())
+            }
+            StopCondition::Stable => write!(f, "stable"),
+            StopCondition::Extinct => write!(f, "extinct"),
+            StopCondition::Escape => write!(f, "escape"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns;
+
+    /// Builds a 20×20 game with `pattern` placed at (`row`, `col`).
+    fn game_with(pattern: &str, row: usize, col: usize) -> Game {
+        let mut game = Game::new((20, 20));
+        game.grid
+            .stamp(patterns::find(pattern).unwrap().cells, row, col);
+        game
+    }
+
+    #[test]
+    fn parses_each_condition() {
+        let parse = |s: &str| s.parse::<StopCondition>();
+        assert_eq!(parse("stable"), Ok(StopCondition::Stable));
+        assert_eq!(parse("extinct"), Ok(StopCondition::Extinct));
+        assert_eq!(parse("escape"), Ok(StopCondition::Escape));
+        for (text, comparison) in [
+            ("pop<500", Comparison::Less),
+            ("pop<=500", Comparison::AtMost),
+            ("pop=500", Comparison::Equal),
+            ("pop>=500", Comparison::AtLeast),
+            ("pop>500", Comparison::Greater),
+        ] {
+            let condition = StopCondition::Population(comparison, 500);
+            assert_eq!(parse(text), Ok(condition));
+            assert_eq!(condition.to_string(), text);
+        }
+        assert_eq!(
+            parse(" pop <= 7 "),
+            Ok(StopCondition::Population(Comparison::AtMost, 7))
+        );
+    }
+
+    #[test]
+    fn at_most_is_not_less_than() {
+        let at_most: StopCondition = "pop<=5".parse().unwrap();
+        let less: StopCondition = "pop<5".parse().unwrap();
+        assert_ne!(at_most, less);
+        assert!(Comparison::AtMost.holds(5, 5));
+        assert!(!Comparison::Less.holds(5, 5));
+        assert!(Comparison::AtLeast.holds(5, 5));
+        assert!(!Comparison::Greater.holds(5, 5));
+    }
+
+    #[test]
+    fn rejects_bad_conditions() {
+        for bad in [
+            "",
+            "pop",
+            "pop500",
+            "pop<",
+            "pop<=x",
+            "pop>-1",
+            "pop=<5",
+            "pop<<5",
+            "popcorn",
+            "Stable",
+            "population>5",
+        ] {
+            assert!(bad.parse::<StopCondition>().is_err(), "{bad:?} parsed");
+        }
+    }
+
+    #[test]
+    fn population_compares_the_live_count() {
+        let game = game_with("glider", 2, 2);
+        assert!(StopCondition::Population(Comparison::Equal, 5).is_met(&game, None));
+        assert!(!StopCondition::Population(Comparison::Greater, 5).is_met(&game, None));
+        assert!(StopCondition::Population(Comparison::Less, 6).is_met(&game, None));
+    }
+
+    #[test]
+    fn stable_is_met_once_the_board_repeats() {
+        let mut game = game_with("blinker", 5, 5);
+        assert!(!StopCondition::Stable.is_met(&game, None));
+        // Only states reached by stepping are remembered, so a repeat shows on the third step
+        game.step();
+        game.step();
+        assert!(!StopCondition::Stable.is_met(&game, None));
+        game.step();
+        assert!(StopCondition::Stable.is_met(&game, None));
+
+        let mut game = game_with("glider", 2, 2);
+        for _ in 0..8 {
+            game.step();
+            assert!(!StopCondition::Stable.is_met(&game, None));
+        }
+    }
+
+    #[test]
+    fn extinct_is_met_when_every_cell_is_dead() {
+        let mut game = Game::new((20, 20));
+        game.grid.set(5, 5, crate::grid::CellState::Alive);
+        assert!(!StopCondition::Extinct.is_met(&game, None));
+        game.step();
+        assert!(StopCondition::Extinct.is_met(&game, None));
+        // An empty board is also stable
+        assert!(StopCondition::Stable.is_met(&game, None));
+    }
+
+    #[test]
+    fn escape_is_met_when_a_glider_leaves_its_start_bounds() {
+        let mut game = game_with("glider", 2, 2);
+        let start = game.grid.live_bounds();
+        assert!(!StopCondition::Escape.is_met(&game, start));
+        // A glider moves one cell diagonally every 4 generations
+        let escaped_at = (1..=8)
+            .find(|_| {
+                game.step();
+                StopCondition::Escape.is_met(&game, start)
+            })
+            .expect("glider escaped");
+        assert!(escaped_at <= 4, "escaped after {escaped_at} generations");
+
+        // A blinker stays within the box its two phases share
+        let mut game = game_with("blinker", 5, 5);
+        let start = game.grid.live_bounds();
+        game.step();
+        assert!(StopCondition::Escape.is_met(&game, start));
+        let start = Some((4, 5, 3, 3));
+        for _ in 0..4 {
+            game.step();
+            assert!(!StopCondition::Escape.is_met(&game, start));
+        }
+
+        // Anything alive escapes an empty start
+        assert!(StopCondition::Escape.is_met(&game, None));
+    }
+}
@@ -1,11 +1,21 @@
 use color_eyre::eyre::WrapErr;
 use ratatui::crossterm::event::{self, Event as CrosstermEvent};
 use std::{
-    sync::mpsc::{self, TryRecvError},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, TryRecvError},
+    },
     thread,
     time::{Duration, Instant},
 };
 
+/// Poll timeout while waiting for the main thread to take the previous tick.
+const TICK_RETRY: Duration = Duration::from_millis(1);
+/// Longest the event thread waits for terminal events before checking for control messages and
+/// due ticks again, so a slow tick interval doesn't delay a speed change or pause.
+const MAX_POLL_TIMEOUT: Duration = Duration::from_millis(50);
+
 /// All possible events.
 #[derive(Clone, Debug)]
 pub enum Event {
@@ -15,6 +25,10 @@ pub enum Event {
     Crossterm(CrosstermEvent),
     /// Application events.
     App(AppEvent),
+    /// Generations done out of the total by a background jump.
+    Progress(u64, u64),
+    /// A background jump's worker stopped; its game is collected from the worker thread.
+    Jumped,
 }
 
 /// High-level application events.
@@ -41,7 +55,7 @@ pub enum ControlMessage {
 
 /// Manages event collection and distribution.
 ///
-/// Spawns a background thread that:
+/// Once started, spawns a background thread that:
 /// - Polls for terminal events
 /// - Generates tick events at configurable intervals
 /// - Handles pause/resume functionality
@@ -53,25 +67,43 @@ pub struct EventHandler {
     event_receiver: mpsc::Receiver<Event>,
     /// Channel for sending control messages to the event thread.
     control_sender: mpsc::Sender<ControlMessage>,
+    /// Channel for receiving control messages, held until the event thread is started.
+    control_receiver: Option<mpsc::Receiver<ControlMessage>>,
+    /// Whether a tick has been sent but not yet received, shared with the event thread.
+    tick_pending: Arc<AtomicBool>,
 }
 
 impl EventHandler {
-    /// Creates a new event handler and spawns the event collection thread.
-    pub fn new(tick_interval: Duration, paused: bool) -> Self {
+    /// Creates a new event handler without touching the terminal.
+    ///
+    /// Control messages sent before [`EventHandler::start`] are queued for the event thread.
+    pub fn new() -> Self {
         let (event_sender, event_receiver) = mpsc::channel();
         let (control_sender, control_receiver) = mpsc::channel();
+        Self {
+            event_sender,
+            event_receiver,
+            control_sender,
+            control_receiver: Some(control_receiver),
+            tick_pending: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Spawns the event collection thread.
+    ///
+    /// Does nothing if the thread has already been started.
+    pub fn start(&mut self, tick_interval: Duration, paused: bool) {
+        let Some(control_receiver) = self.control_receiver.take() else {
+            return;
+        };
         let actor = EventThread::new(
-            event_sender.clone(),
+            self.event_sender.clone(),
             control_receiver,
+            Arc::clone(&self.tick_pending),
             tick_interval,
             paused,
         );
         thread::spawn(|| actor.run());
-        Self {
-            event_sender,
-            event_receiver,
-            control_sender,
-        }
     }
 
     /// Receives an event from the sender.
@@ -84,7 +116,11 @@ impl EventHandler {
     /// error occurs in the event thread. In practice, this should not happen unless there is a
     /// problem with the underlying terminal.
     pub fn next(&self) -> color_eyre::Result<Event> {
-        Ok(self.event_receiver.recv()?)
+        let event = self.event_receiver.recv()?;
+        if let Event::Tick = event {
+            self.tick_pending.store(false, Ordering::Release);
+        }
+        Ok(event)
     }
 
     /// Queue an app event to be sent to the event receiver.
@@ -97,6 +133,11 @@ impl EventHandler {
         let _ = self.event_sender.send(Event::App(app_event));
     }
 
+    /// Returns a sender for events produced by other worker threads.
+    pub fn sender(&self) -> mpsc::Sender<Event> {
+        self.event_sender.clone()
+    }
+
     /// Updates the tick event interval.
     pub fn set_tick_interval(&self, interval: Duration) {
         let _ = self
@@ -115,12 +156,20 @@ impl EventHandler {
     }
 }
 
+impl Default for EventHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Background thread that collects events from multiple sources.
 struct EventThread {
     /// Channel for sending events to the main thread.
     event_sender: mpsc::Sender<Event>,
     /// Channel for receiving control messages.
     control_receiver: mpsc::Receiver<ControlMessage>,
+    /// Whether a tick has been sent but not yet received by the main thread.
+    tick_pending: Arc<AtomicBool>,
     /// Interval between generated tick events.
     tick_interval: Duration,
     /// Whether tick generation is paused.
@@ -132,12 +181,14 @@ impl EventThread {
     fn new(
         event_sender: mpsc::Sender<Event>,
         control_receiver: mpsc::Receiver<ControlMessage>,
+        tick_pending: Arc<AtomicBool>,
         tick_interval: Duration,
         paused: bool,
     ) -> Self {
         Self {
             event_sender,
             control_receiver,
+            tick_pending,
             tick_interval,
             paused,
         }
@@ -146,6 +197,7 @@ impl EventThread {
     /// Runs the event thread.
     ///
     /// This function emits tick events at a fixed rate and polls for crossterm events in between.
+    /// At most one tick is queued at a time.
     fn run(mut self) -> color_eyre::Result<()> {
         let mut last_tick = Instant::now();
 
@@ -159,29 +211,38 @@ impl EventThread {
                 }
             }
 
-            // Calculate poll timeout
-            let elapsed = last_tick.elapsed();
-            let time_until_tick = if self.paused {
-                // Longer timeout when paused to reduce CPU usage
-                Duration::from_millis(100)
-            } else {
-                self.tick_interval.saturating_sub(elapsed)
-            };
-
-            // Generate tick if due
-            if !self.paused && time_until_tick == Duration::ZERO {
-                last_tick = Instant::now();
-                self.send(Event::Tick);
+            let mut timeout = self.poll_timeout(last_tick.elapsed());
+
+            // Generate tick if due, unless the previous one hasn't been handled yet so ticks
+            // never pile up (e.g. with a zero interval)
+            if !self.paused && timeout == Duration::ZERO {
+                if self.tick_pending.swap(true, Ordering::AcqRel) {
+                    timeout = TICK_RETRY;
+                } else {
+                    last_tick = Instant::now();
+                    self.send(Event::Tick);
+                }
             }
 
-            // Poll for terminal events
-            if event::poll(time_until_tick).wrap_err("failed to poll for crossterm events")? {
+            if event::poll(timeout).wrap_err("failed to poll for crossterm events")? {
                 let event = event::read().wrap_err("failed to read crossterm event")?;
                 self.send(Event::Crossterm(event));
             }
         }
     }
 
+    /// Returns how long to poll for terminal events, `since_tick` after the last tick: until the
+    /// next tick is due, waking up at least every [`MAX_POLL_TIMEOUT`] for control messages.
+    fn poll_timeout(&self, since_tick: Duration) -> Duration {
+        if self.paused {
+            MAX_POLL_TIMEOUT
+        } else {
+            self.tick_interval
+                .saturating_sub(since_tick)
+                .min(MAX_POLL_TIMEOUT)
+        }
+    }
+
     /// Sends an event to the receiver.
     fn send(&self, event: Event) {
         // Ignores the result because shutting down the app drops the receiver, which causes the send
@@ -204,3 +265,82 @@ impl EventThread {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handler_works_without_a_terminal_until_started() {
+        // Nothing here touches the terminal, which tests don't have
+        let mut events = EventHandler::new();
+        events.set_tick_interval(Duration::from_millis(10));
+        events.pause();
+        events.resume();
+
+        events.send(AppEvent::Quit);
+        let sender = events.sender();
+        thread::spawn(move || sender.send(Event::Progress(1, 2)).unwrap())
+            .join()
+            .unwrap();
+        assert!(matches!(events.next().unwrap(), Event::App(AppEvent::Quit)));
+        assert!(matches!(events.next().unwrap(), Event::Progress(1, 2)));
+
+        // Control messages wait for the event thread in the order they were sent
+        let control = events.control_receiver.as_ref().unwrap();
+        assert!(matches!(
+            control.try_recv(),
+            Ok(ControlMessage::SetTickInterval(interval)) if interval == Duration::from_millis(10)
+        ));
+        assert!(matches!(control.try_recv(), Ok(ControlMessage::Pause)));
+        assert!(matches!(control.try_recv(), Ok(ControlMessage::Resume)));
+        assert!(matches!(control.try_recv(), Err(TryRecvError::Empty)));
+    }
+
+    #[test]
+    fn receiving_a_tick_lets_the_next_one_be_sent() {
+        let events = EventHandler::default();
+        events.tick_pending.store(true, Ordering::Release);
+        events.sender().send(Event::Tick).unwrap();
+        assert!(matches!(events.next().unwrap(), Event::Tick));
+        assert!(!events.tick_pending.load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn control_messages_wait_at_most_one_poll_timeout() {
+        let (event_sender, _event_receiver) = mpsc::channel();
+        let (_control_sender, control_receiver) = mpsc::channel();
+        let mut thread = EventThread::new(
+            event_sender,
+            control_receiver,
+            Arc::new(AtomicBool::new(false)),
+            Duration::from_secs(3600),
+            false,
+        );
+
+        // However slow the ticks, and while paused, a pause or speed change is seen promptly
+        for since_tick in [
+            Duration::ZERO,
+            Duration::from_secs(1),
+            Duration::from_secs(3599),
+        ] {
+            assert_eq!(thread.poll_timeout(since_tick), MAX_POLL_TIMEOUT);
+        }
+        thread.handle_control_message(ControlMessage::Pause);
+        assert_eq!(thread.poll_timeout(Duration::ZERO), MAX_POLL_TIMEOUT);
+        assert!(MAX_POLL_TIMEOUT <= Duration::from_millis(50));
+
+        // Fast ticks wake the thread just in time for the next one
+        thread.handle_control_message(ControlMessage::Resume);
+        thread.handle_control_message(ControlMessage::SetTickInterval(Duration::from_millis(30)));
+        assert_eq!(
+            thread.poll_timeout(Duration::from_millis(10)),
+            Duration::from_millis(20)
+        );
+        assert_eq!(
+            thread.poll_timeout(Duration::from_millis(30)),
+            Duration::ZERO
+        );
+        assert_eq!(thread.poll_timeout(Duration::from_secs(5)), Duration::ZERO);
+    }
+}
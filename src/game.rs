@@ -1,17 +1,56 @@
-use std::time::Duration;
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt,
+    time::{Duration, Instant},
+};
 
-use crate::grid::{CellState, Grid};
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::index};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, ser::SerializeStruct};
 
-/// Bounds for tick interval.
+use crate::{
+    grid::{CellState, Grid, GridBackend, GridError},
+    history::{HistoryEntry, PopulationHistory},
+    mask::Mask,
+    patterns::{self, Pattern},
+    rule::{ParseRuleError, Rule},
+    scene::Scene,
+};
+
+/// Default bounds for tick interval.
 const MIN_INTERVAL: Duration = Duration::from_millis(30);
 const MAX_INTERVAL: Duration = Duration::from_millis(1000);
-/// Step size for speed adjustments.
+/// Default step size for speed adjustments.
 const INTERVAL_STEP: Duration = Duration::from_millis(10);
+/// Number of recent generations compared against when detecting a period.
+const PERIOD_WINDOW: usize = 64;
+/// Generations a trial soup runs for before its population is taken as settled.
+const SETTLE_GENERATIONS: u64 = 1000;
+/// Highest density tried when searching for a density, beyond which soups mostly die of
+/// overcrowding.
+const MAX_SEARCH_DENSITY: f32 = 0.5;
+/// Magic bytes starting the binary format written by [`Game::to_bytes`].
+const BINARY_MAGIC: &[u8; 4] = b"RGOL";
+/// Version of the binary format written by [`Game::to_bytes`].
+const BINARY_VERSION: u8 = 1;
 /// Default tick interval.
 const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+/// Named tick intervals for jumping straight to a speed, slowest first.
+///
+/// These are the intervals under the default bounds; see [`Game::preset_interval`] for how they
+/// follow configured ones.
+pub const SPEED_PRESETS: [(&str, Duration); 5] = [
+    ("slowest", Duration::from_millis(1000)),
+    ("slow", Duration::from_millis(500)),
+    ("medium", Duration::from_millis(200)),
+    ("fast", Duration::from_millis(100)),
+    ("fastest", MIN_INTERVAL),
+];
 
 /// Current state of the simulation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GameState {
     /// Simulation is actively running.
     Running,
@@ -19,80 +58,808 @@ pub enum GameState {
     Paused,
 }
 
+/// Cell changes produced by a single simulation step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StepStats {
+    /// Number of dead cells that became alive.
+    pub births: usize,
+    /// Number of live cells that died.
+    pub deaths: usize,
+}
+
+impl StepStats {
+    /// Returns the net change in population.
+    pub fn net_growth(&self) -> isize {
+        self.births as isize - self.deaths as isize
+    }
+
+    /// Returns the total number of cells that changed state.
+    pub fn activity(&self) -> usize {
+        self.births + self.deaths
+    }
+}
+
+/// Callback run after every step with the game as it stands afterwards; see
+/// [`Game::set_on_step`].
+pub type StepHook = Box<dyn FnMut(&Game) + Send>;
+
+/// A game's [`StepHook`], if one is registered.
+///
+/// Clones of a game start without one, since a boxed closure can't be cloned.
+#[derive(Default)]
+struct OnStep(Option<StepHook>);
+
+impl Clone for OnStep {
+    fn clone(&self) -> Self {
+        Self(None)
+    }
+}
+
+impl fmt::Debug for OnStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.0.is_some() { "Some(..)" } else { "None" })
+    }
+}
+
+/// A saved copy of the board state that can be restored later.
+///
+/// Taken with [`Game::snapshot`] and applied with [`Game::restore`]; the contents can't be
+/// changed in between.
+#[derive(Debug, Clone)]
+pub struct GameSnapshot {
+    /// Grid state at the time of the snapshot.
+    grid: Grid,
+    /// Generation at the time of the snapshot.
+    generation: u64,
+}
+
+impl GameSnapshot {
+    /// Returns the grid state at the time of the snapshot.
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// Returns the generation at the time of the snapshot.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
 /// Manages core game logic and simulation state for Conway's Game of Life.
 #[derive(Debug, Clone)]
 pub struct Game {
     /// Current generation's grid state.
     pub grid: Grid,
-    /// Next generation's grid (pre-allocated for performance).
-    next_grid: Grid,
 
+    /// Birth and survival rule applied each step.
+    pub rule: Rule,
     /// Simulation state.
     pub state: GameState,
     /// Time between simulation steps.
     pub tick_interval: Duration,
+    /// Shortest tick interval reachable by speeding up.
+    min_interval: Duration,
+    /// Longest tick interval reachable by slowing down.
+    max_interval: Duration,
+    /// Amount the tick interval changes by per speed adjustment.
+    interval_step: Duration,
     /// Number of generations that have elapsed.
     pub generation: u64,
+    /// Births and deaths from the most recent step.
+    pub last_step: StepStats,
+    /// Generation at which to automatically pause, if any.
+    pub run_target: Option<u64>,
+    /// Generations left to run before automatically pausing, if counting down.
+    pub pause_after: Option<u64>,
+    /// Population data for recent generations since the grid was last reset.
+    pub history: PopulationHistory,
+    /// Grid state hashes of recent generations, oldest first, for detecting periods.
+    recent_hashes: VecDeque<u64>,
+    /// Shape hashes and bounding box corners of recent generations, oldest first, for
+    /// detecting spaceships.
+    recent_shapes: VecDeque<(u64, (usize, usize))>,
+    /// Generation at which the current period first started repeating, if one is detected.
+    stabilized_at: Option<u64>,
+
+    /// Running time accumulated since the last reset, excluding the current running stretch.
+    run_time: Duration,
+    /// When the simulation last started running, or `None` while paused.
+    resumed_at: Option<Instant>,
+    /// Generation at the last reset, for computing the average rate.
+    run_start_generation: u64,
+    /// Rule used by the last full step, which unchanged cells are known to be stable under.
+    stepped_rule: Rule,
+    /// Whether steps follow the second-order reversible rule instead of the plain rule.
+    reversible: bool,
+    /// Whether each cell was alive in the previous generation, in row-major order, for the
+    /// reversible rule. Empty if every cell was dead.
+    previous: Vec<bool>,
+    /// Source of randomness for randomizing and perturbing the grid.
+    rng: StdRng,
+    /// Callback run after every step, if any.
+    on_step: OnStep,
 }
 
 impl Game {
     /// Creates a new game with specified grid size
     pub fn new(grid_size: (usize, usize)) -> Self {
-        let grid = Grid::new(grid_size.0, grid_size.1);
-        let next_grid = Grid::new(grid_size.0, grid_size.1);
+        Self::with_rng(grid_size, StdRng::from_os_rng())
+    }
+
+    /// Creates a new game with specified grid size, drawing randomness from `rng`.
+    ///
+    /// Passing a seeded generator makes every randomizing operation reproducible.
+    pub fn with_rng(grid_size: (usize, usize), rng: StdRng) -> Self {
+        Self::with_grid(Grid::new(grid_size.0, grid_size.1), rng)
+    }
 
+    /// Creates a new game with specified grid size.
+    ///
+    /// Unlike [`Game::new`], fails instead of panicking if either dimension is zero or the
+    /// grid would have more than [`DEFAULT_MAX_CELLS`] cells.
+    ///
+    /// [`DEFAULT_MAX_CELLS`]: crate::grid::DEFAULT_MAX_CELLS
+    pub fn try_new(grid_size: (usize, usize)) -> Result<Self, GridError> {
+        let grid = Grid::try_new(grid_size.0, grid_size.1)?;
+        Ok(Self::with_grid(grid, StdRng::from_os_rng()))
+    }
+
+    /// Creates a new game around an existing grid.
+    fn with_grid(grid: Grid, rng: StdRng) -> Self {
         Self {
             grid,
-            next_grid,
 
+            rule: Rule::default(),
             state: GameState::Running,
             tick_interval: DEFAULT_INTERVAL,
+            min_interval: MIN_INTERVAL,
+            max_interval: MAX_INTERVAL,
+            interval_step: INTERVAL_STEP,
             generation: 0,
+            last_step: StepStats::default(),
+            run_target: None,
+            pause_after: None,
+            history: PopulationHistory::default(),
+            recent_hashes: VecDeque::with_capacity(PERIOD_WINDOW + 1),
+            recent_shapes: VecDeque::with_capacity(PERIOD_WINDOW + 1),
+            stabilized_at: None,
+
+            run_time: Duration::ZERO,
+            resumed_at: Some(Instant::now()),
+            run_start_generation: 0,
+            stepped_rule: Rule::default(),
+            reversible: false,
+            previous: Vec::new(),
+            rng,
+            on_step: OnStep::default(),
         }
     }
 
+    /// Registers a callback to run after every step, replacing any previous one.
+    ///
+    /// The callback sees the game as it stands after each step, so embedders can collect
+    /// metrics, drive their own rendering, or check a custom stop condition without writing
+    /// their own loop. It runs after every kind of forward step ([`Game::step`],
+    /// [`Game::step_region`], and so on), but not after [`Game::step_back_reversible`] or edits.
+    /// Without a callback a step only checks that there is none.
+    ///
+    /// The callback must not step the game itself, e.g. through a shared handle: it's taken out
+    /// of the game while it runs, so the nested step would silently skip it. Clones of the game
+    /// don't keep the callback.
+    pub fn set_on_step(&mut self, hook: StepHook) {
+        self.on_step = OnStep(Some(hook));
+    }
+
+    /// Removes the callback registered with [`Game::set_on_step`], returning it if there was
+    /// one.
+    pub fn take_on_step(&mut self) -> Option<StepHook> {
+        self.on_step.0.take()
+    }
+
     /// Advances the simulation by one generation.
     ///
-    /// Applies Game of Life rules:
+    /// Applies the game's rule, which defaults to Conway's Game of Life:
     /// - Live cells with 2-3 neighbors -> alive
     /// - Dead cells with 3 neighbors -> alive
     /// - All other cells -> dead
+    ///
+    /// Only cells that changed since the last step, and their neighbors, can change in this one,
+    /// so the rest of the grid is skipped unless the rule has changed in between.
+    ///
+    /// In reversible mode this is [`Game::step_reversible`] instead.
     pub fn step(&mut self) {
-        for row in 0..self.grid.height {
-            for col in 0..self.grid.width {
-                let current_state = self.grid.get(row, col).unwrap_or(CellState::Dead);
-                let neighbors = self.grid.count_neighbors(row, col);
-
-                let new_state = match (current_state, neighbors) {
-                    (CellState::Alive, 2 | 3) => CellState::Alive, // survival
-                    (CellState::Dead, 3) => CellState::Alive,      // birth
-                    _ => CellState::Dead,                          // death
-                };
+        if self.reversible {
+            self.step_reversible();
+            return;
+        }
 
-                self.next_grid.set(row, col, new_state);
+        let changes = self.grid.take_changes();
+        let rule_changed = std::mem::replace(&mut self.stepped_rule, self.rule) != self.rule;
+
+        match changes {
+            Some(changes) if !rule_changed => {
+                let grid = &self.grid;
+                let mut candidates: Vec<(usize, usize)> = changes
+                    .iter()
+                    .flat_map(|&(row, col)| {
+                        (-1..=1).flat_map(move |dr| {
+                            (-1..=1).filter_map(move |dc| {
+                                grid.resolve(row as isize + dr, col as isize + dc)
+                            })
+                        })
+                    })
+                    .collect();
+                candidates.sort_unstable();
+                candidates.dedup();
+                self.step_cells(candidates);
+            }
+            _ => {
+                let (height, width) = (self.grid.height(), self.grid.width());
+                self.step_cells((0..height).flat_map(|row| (0..width).map(move |col| (row, col))));
             }
         }
+    }
 
-        // Swap grids
-        std::mem::swap(&mut self.grid, &mut self.next_grid);
+    /// Steps `n` generations and returns a checksum of the population after each one and the
+    /// final arrangement of live cells.
+    ///
+    /// Meant for benchmarks: the checksum depends on every step, so handing it to
+    /// [`std::hint::black_box`] keeps the optimizer from skipping any, and it only depends on
+    /// which cells are alive, so it stays the same when stepping is reimplemented.
+    pub fn step_n_bench(&mut self, n: u64) -> u64 {
+        let populations = (0..n).fold(0u64, |checksum, _| {
+            self.step();
+            checksum
+                .rotate_left(7)
+                .wrapping_add(self.grid.live_count() as u64)
+        });
+        populations ^ self.grid.shape_hash().0
+    }
+
+    /// Advances the simulation by one generation inside a rectangular region only.
+    ///
+    /// Cells outside the region are frozen: they still count as neighbors of cells inside, but
+    /// are never modified. The region is clipped to the grid's bounds.
+    pub fn step_region(&mut self, top: usize, left: usize, height: usize, width: usize) {
+        let bottom = top.saturating_add(height).min(self.grid.height());
+        let right = left.saturating_add(width).min(self.grid.width());
+        self.step_cells((top..bottom).flat_map(|row| (left..right).map(move |col| (row, col))));
+    }
+
+    /// Advances the simulation by one generation inside a mask only.
+    ///
+    /// Like [`Game::step_region`], cells outside the mask still count as neighbors but are never
+    /// modified.
+    pub fn step_masked(&mut self, mask: &Mask) {
+        let (width, height) = self.grid.dimensions();
+        self.step_cells(
+            mask.iter()
+                .filter(|&(row, col)| row < height && col < width),
+        );
+    }
+
+    /// Advances the simulation by one generation under the second-order reversible rule.
+    ///
+    /// Each cell's next state is what the game's rule gives it, flipped if the cell was alive in
+    /// the previous generation. The previous generation can be recovered the same way, so
+    /// [`Game::step_back_reversible`] undoes this exactly.
+    pub fn step_reversible(&mut self) {
+        // Change tracking only helps the plain rule
+        self.grid.take_changes();
+        let updates = self.reversible_updates();
+        self.previous = self.liveness();
+        self.apply_step(updates);
+    }
+
+    /// Steps the second-order reversible rule backwards by one generation.
+    ///
+    /// Population history isn't recorded going backwards and trails are cleared. Returns
+    /// `false`, leaving the game alone, at generation 0.
+    pub fn step_back_reversible(&mut self) -> bool {
+        if self.generation == 0 {
+            return false;
+        }
+        self.grid.take_changes();
+        let before = self.liveness();
+
+        // Going back from (previous, current) is going forward from (current, previous)
+        self.swap_previous();
+        let updates = self.reversible_updates();
+        self.previous = self.liveness();
+        for (row, col, state) in updates {
+            self.grid.set(row, col, state);
+        }
+        self.swap_previous();
+
+        let mut stats = StepStats::default();
+        for (&was_alive, (_, state)) in before.iter().zip(self.grid.iter_cells()) {
+            match (was_alive, state.is_alive()) {
+                (false, true) => stats.births += 1,
+                (true, false) => stats.deaths += 1,
+                _ => {}
+            }
+        }
+        self.grid.clear_trails();
+        self.generation -= 1;
+        self.grid.set_log_generation(self.generation);
+        self.last_step = stats;
+        self.recent_hashes.clear();
+        self.recent_shapes.clear();
+        self.stabilized_at = None;
+        true
+    }
+
+    /// Returns true if steps follow the second-order reversible rule.
+    pub fn is_reversible(&self) -> bool {
+        self.reversible
+    }
+
+    /// Switches between the plain rule and the second-order reversible rule.
+    ///
+    /// The previous generation starts out empty.
+    pub fn set_reversible(&mut self, reversible: bool) {
+        self.reversible = reversible;
+        self.previous.clear();
+        // Cells the reversible rule left alone aren't necessarily stable under the plain rule
+        self.grid.mark_all_changed();
+    }
+
+    /// Returns the changes the reversible rule makes to every cell but walls.
+    fn reversible_updates(&self) -> Vec<(usize, usize, CellState)> {
+        let mut updates = Vec::new();
+        for ((row, col), current_state) in self.grid.iter_cells() {
+            if self.grid.is_wall(row, col) {
+                continue;
+            }
+            let neighbors = self.rule_count(row, col);
+            let alive = self.rule.next_state(current_state, neighbors).is_alive()
+                != self.previous_alive(row * self.grid.width() + col);
+            if alive != current_state.is_alive() {
+                updates.push((row, col, CellState::from_alive(alive)));
+            }
+        }
+        updates
+    }
+
+    /// Returns whether each cell is alive, in row-major order.
+    fn liveness(&self) -> Vec<bool> {
+        self.grid
+            .iter_cells()
+            .map(|(_, state)| state.is_alive())
+            .collect()
+    }
+
+    /// Returns true if the cell at `idx` was alive in the previous generation.
+    fn previous_alive(&self, idx: usize) -> bool {
+        self.previous.get(idx).copied().unwrap_or(false)
+    }
+
+    /// Exchanges the current and previous generations.
+    fn swap_previous(&mut self) {
+        let current = self.liveness();
+        for row in 0..self.grid.height() {
+            for col in 0..self.grid.width() {
+                let alive = self.previous_alive(row * self.grid.width() + col);
+                self.grid.set(row, col, CellState::from_alive(alive));
+            }
+        }
+        self.previous = current;
+    }
+
+    /// Applies the rule to the given cells, then updates trails and stats for a new generation.
+    ///
+    /// All cells are evaluated against the current generation before any of them change. Walls
+    /// are skipped.
+    fn step_cells(&mut self, cells: impl IntoIterator<Item = (usize, usize)>) {
+        let mut updates = Vec::new();
+        for (row, col) in cells {
+            if self.grid.is_wall(row, col) {
+                continue;
+            }
+            let current_state = self.grid.get(row, col).unwrap_or(CellState::Dead);
+            let neighbors = self.rule_count(row, col);
+
+            let new_state = self.rule.next_state(current_state, neighbors);
+            if new_state != current_state {
+                updates.push((row, col, new_state));
+            }
+        }
+        self.apply_step(updates);
+    }
+
+    /// Counts the live cells the rule looks at for a cell: its neighbors, and the cell itself if
+    /// the rule counts it.
+    fn rule_count(&self, row: usize, col: usize) -> u8 {
+        if self.rule.counts_self() {
+            self.grid.count_self_and_neighbors(row, col)
+        } else {
+            self.grid.count_neighbors(row, col)
+        }
+    }
+
+    /// Applies a step's cell changes, then updates trails and stats for a new generation.
+    fn apply_step(&mut self, updates: Vec<(usize, usize, CellState)>) {
+        let births = updates
+            .iter()
+            .filter(|(_, _, state)| state.is_alive())
+            .count();
+        let stats = StepStats {
+            births,
+            deaths: updates.len() - births,
+        };
+        // An edit since the last step breaks any cycle the grid was in
+        let stabilized_at = self.stabilized_at();
+
+        // Age cells and trails before births and deaths start new ones
+        self.grid.age_cells();
+        self.grid.set_log_generation(self.generation + 1);
+        for (row, col, state) in updates {
+            self.grid.set(row, col, state);
+        }
 
         // Update stats
         self.generation += 1;
+        self.last_step = stats;
+        self.history.push(HistoryEntry {
+            generation: self.generation,
+            population: self.grid.population,
+            births: stats.births,
+            deaths: stats.deaths,
+        });
+        if self.recent_hashes.len() > PERIOD_WINDOW {
+            self.recent_hashes.pop_front();
+        }
+        self.recent_hashes.push_back(self.grid.state_hash());
+        if self.recent_shapes.len() > PERIOD_WINDOW {
+            self.recent_shapes.pop_front();
+        }
+        self.recent_shapes.push_back(self.grid.shape_hash());
+        // Keep the earliest generation of an ongoing cycle
+        self.stabilized_at = self
+            .period()
+            .map(|period| stabilized_at.unwrap_or(self.generation - period));
+
+        // Count down relative runs, pausing once the last generation is reached
+        if let Some(left) = &mut self.pause_after {
+            *left = left.saturating_sub(1);
+            if *left == 0 {
+                self.pause_after = None;
+                if !self.is_paused() {
+                    self.toggle_pause();
+                }
+            }
+        }
+
+        if let Some(mut hook) = self.on_step.0.take() {
+            hook(self);
+            self.on_step.0 = Some(hook);
+        }
     }
 
     /// Resizes the grid while preserving existing cells where possible.
+    ///
+    /// The reversible rule's previous generation is forgotten.
+    ///
+    /// # Panics
+    ///
+    /// Panics on dimensions [`Grid::resize`] rejects; see [`Game::try_resize`].
     pub fn resize(&mut self, new_width: usize, new_height: usize) {
-        if self.grid.width == new_width && self.grid.height == new_height {
-            return;
+        if let Err(e) = self.try_resize(new_width, new_height) {
+            panic!("Invalid grid dimensions: {e}");
+        }
+    }
+
+    /// Resizes the grid like [`Game::resize`], leaving the game untouched on dimensions
+    /// [`Grid::try_resize`] rejects.
+    pub fn try_resize(&mut self, new_width: usize, new_height: usize) -> Result<(), GridError> {
+        if self.grid.width() == new_width && self.grid.height() == new_height {
+            return Ok(());
+        }
+        self.grid.try_resize(new_width, new_height)?;
+        self.previous.clear();
+        Ok(())
+    }
+
+    /// Captures the current grid and generation.
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            grid: self.grid.clone(),
+            generation: self.generation,
+        }
+    }
+
+    /// Restores the grid and generation from a snapshot.
+    ///
+    /// The snapshot's grid dimensions replace the current ones. The birth log stays on or off
+    /// as it was.
+    pub fn restore(&mut self, snapshot: &GameSnapshot) {
+        let birth_log = self.grid.birth_log_enabled();
+        self.grid = snapshot.grid.clone();
+        self.grid.set_birth_log(birth_log);
+        self.grid.mark_all_changed();
+        self.generation = snapshot.generation;
+        self.last_step = StepStats::default();
+        self.reset_run_stats();
+    }
+
+    /// Encodes the board, generation, and rule in a compact binary format.
+    ///
+    /// Much faster to write and read than RLE for large boards. All numbers are little-endian:
+    ///
+    /// | Bytes       | Contents                                                  |
+    /// | ----------- | --------------------------------------------------------- |
+    /// | 4           | magic bytes `RGOL`                                        |
+    /// | 1           | format version, currently `1`                             |
+    /// | 4, 4        | width and height as `u32`                                 |
+    /// | 8           | generation as `u64`                                       |
+    /// | 1, n        | length of the rule in B/S notation, then the rule itself  |
+    /// | ⌈w × h / 8⌉ | cells in row-major order, 8 per byte from the lowest bit  |
+    ///
+    /// Walls, trails, and statistics aren't saved.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (width, height) = self.grid.dimensions();
+        let rule = self.rule.to_string();
+        let mut bytes = Vec::with_capacity(23 + rule.len() + (width * height).div_ceil(8));
+        bytes.extend_from_slice(BINARY_MAGIC);
+        bytes.push(BINARY_VERSION);
+        // The cell limit keeps both dimensions well within a u32
+        bytes.extend_from_slice(&(width as u32).to_le_bytes());
+        bytes.extend_from_slice(&(height as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.generation.to_le_bytes());
+        bytes.push(rule.len() as u8);
+        bytes.extend_from_slice(rule.as_bytes());
+
+        let cells_start = bytes.len();
+        bytes.resize(cells_start + (width * height).div_ceil(8), 0);
+        for (row, col) in self.grid.iter_alive_cells() {
+            let idx = row * width + col;
+            bytes[cells_start + idx / 8] |= 1 << (idx % 8);
+        }
+        bytes
+    }
+
+    /// Decodes a game written by [`Game::to_bytes`].
+    ///
+    /// Like a game from [`Game::new`], it starts running at the default speed. Fails if the
+    /// magic bytes or version don't match, or the data is cut short or malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut rest = bytes;
+        let mut take = |len: usize| -> Result<&[u8], DecodeError> {
+            let (taken, remaining) = rest.split_at_checked(len).ok_or(DecodeError::Truncated)?;
+            rest = remaining;
+            Ok(taken)
+        };
+        let u32_at = |bytes: &[u8]| u32::from_le_bytes(bytes.try_into().expect("4 bytes"));
+
+        if take(4)? != BINARY_MAGIC {
+            return Err(DecodeError::BadMagic);
+        }
+        match take(1)?[0] {
+            BINARY_VERSION => {}
+            version => return Err(DecodeError::UnsupportedVersion(version)),
+        }
+        let width = u32_at(take(4)?) as usize;
+        let height = u32_at(take(4)?) as usize;
+        let generation = u64::from_le_bytes(take(8)?.try_into().expect("8 bytes"));
+        let rule_len = take(1)?[0] as usize;
+        let rule = std::str::from_utf8(take(rule_len)?)
+            .map_err(|_| DecodeError::Rule(ParseRuleError::Format))
+            .and_then(|rule| Rule::parse(rule).map_err(DecodeError::Rule))?;
+
+        let mut grid = Grid::try_new(width, height).map_err(DecodeError::Grid)?;
+        let cells = take((width * height).div_ceil(8))?;
+        if !rest.is_empty() {
+            return Err(DecodeError::TrailingBytes);
         }
-        self.grid.resize(new_width, new_height);
-        self.next_grid = Grid::new(new_width, new_height);
+        for (byte_idx, &byte) in cells.iter().enumerate().filter(|(_, byte)| **byte != 0) {
+            for bit in (0..8).filter(|bit| byte & (1 << bit) != 0) {
+                let idx = byte_idx * 8 + bit;
+                if idx >= width * height {
+                    return Err(DecodeError::TrailingBytes);
+                }
+                grid.set(idx / width, idx % width, CellState::Alive);
+            }
+        }
+
+        let mut game = Self::with_grid(grid, StdRng::from_os_rng());
+        game.rule = rule;
+        game.stepped_rule = rule;
+        game.generation = generation;
+        game.reset_run_stats();
+        Ok(game)
+    }
+
+    /// Clears the grid and places a pattern in its center.
+    pub fn load_pattern(&mut self, pattern: &Pattern) {
+        self.load_cells(pattern.cells);
+    }
+
+    /// Clears the grid and places live cells, given as (row, col) offsets, in its center.
+    ///
+    /// Cells that fall outside the grid are skipped. Returns the number of cells placed.
+    pub fn load_cells(&mut self, cells: &[(usize, usize)]) -> usize {
+        self.clear();
+        let (height, width) = patterns::bounding_size(cells);
+        let top = self.grid.height().saturating_sub(height) / 2;
+        let left = self.grid.width().saturating_sub(width) / 2;
+        self.grid.stamp(cells, top, left)
+    }
+
+    /// Clears the grid and places every pattern in a scene.
+    ///
+    /// Entries that can't be placed (unknown pattern, unreadable file, or extending past the
+    /// grid's edges) are skipped. Returns an error message for each skipped entry.
+    pub fn load_scene(&mut self, scene: &Scene) -> Vec<String> {
+        self.clear();
+
+        let mut errors = Vec::new();
+        for entry in &scene.entries {
+            let cells = match entry.cells() {
+                Ok(cells) => cells,
+                Err(e) => {
+                    errors.push(format!("line {}: {e}", entry.line));
+                    continue;
+                }
+            };
+
+            let (height, width) = patterns::bounding_size(&cells);
+            let fits = |start: usize, len: usize, limit: usize| {
+                start.checked_add(len).is_some_and(|end| end <= limit)
+            };
+            if !fits(entry.row, height, self.grid.height())
+                || !fits(entry.col, width, self.grid.width())
+            {
+                errors.push(format!(
+                    "line {}: {} at ({}, {}) is out of bounds",
+                    entry.line, entry.source, entry.row, entry.col
+                ));
+                continue;
+            }
+
+            self.grid.stamp(&cells, entry.row, entry.col);
+        }
+        errors
     }
 
     /// Clears the grid and resets stats.
     pub fn clear(&mut self) {
         self.grid.clear();
         self.generation = 0;
+        self.last_step = StepStats::default();
+        self.reset_run_stats();
+    }
+
+    /// Makes the current state generation 0, leaving the grid untouched.
+    ///
+    /// Unlike [`Game::clear`], only the generation count, history, and period detection start
+    /// over.
+    pub fn reset_generation(&mut self) {
+        self.generation = 0;
+        self.reset_run_stats();
+    }
+
+    /// Kills every cell outside a rectangular region, leaving the cells inside untouched.
+    ///
+    /// The region is clipped to the grid's bounds. The generation carries on as for any other
+    /// edit.
+    pub fn clear_outside(&mut self, top: usize, left: usize, height: usize, width: usize) {
+        let bottom = top.saturating_add(height);
+        let right = left.saturating_add(width);
+        let outside: Vec<_> = self
+            .grid
+            .iter_alive_cells()
+            .filter(|&(row, col)| !(top..bottom).contains(&row) || !(left..right).contains(&col))
+            .collect();
+        for (row, col) in outside {
+            self.grid.set(row, col, CellState::Dead);
+        }
+    }
+
+    /// Kills every cell inside a mask, leaving the cells outside untouched.
+    ///
+    /// The generation carries on as for any other edit.
+    pub fn clear_masked(&mut self, mask: &Mask) {
+        for (row, col) in mask.iter() {
+            self.grid.set(row, col, CellState::Dead);
+        }
+    }
+
+    /// Returns the (width, height) of a grid fitting a `width`×`height` pattern with `margin`
+    /// dead cells on every side.
+    ///
+    /// Saturates at `usize::MAX` rather than overflowing, leaving [`Grid::check_dimensions`] to
+    /// reject the result.
+    pub fn fit_size(width: usize, height: usize, margin: usize) -> (usize, usize) {
+        let margin = margin.saturating_mul(2);
+        (width.saturating_add(margin), height.saturating_add(margin))
+    }
+
+    /// Resizes the grid to just fit its live cells, with `margin` dead cells on every side.
+    ///
+    /// Walls and trails are cleared and the generation carries on. Returns `false`, leaving the
+    /// grid alone, if it is empty, and fails, also leaving it alone, if the fitted grid would be
+    /// too large.
+    pub fn fit_to_pattern(&mut self, margin: usize) -> Result<bool, GridError> {
+        self.fit_to_pattern_within(margin, usize::MAX, usize::MAX)
+    }
+
+    /// Resizes the grid like [`Game::fit_to_pattern`], but to at most `max_width`×`max_height`
+    /// cells, clipping the pattern's bottom and right edges if it doesn't fit.
+    pub fn fit_to_pattern_within(
+        &mut self,
+        margin: usize,
+        max_width: usize,
+        max_height: usize,
+    ) -> Result<bool, GridError> {
+        let Some((top, left, height, width)) = self.grid.live_bounds() else {
+            return Ok(false);
+        };
+        let cells: Vec<_> = self
+            .grid
+            .iter_alive_cells()
+            .map(|(row, col)| (row - top, col - left))
+            .collect();
+
+        let (new_width, new_height) = Self::fit_size(width, height, margin);
+        self.try_resize(new_width.min(max_width), new_height.min(max_height))?;
+        self.grid.clear();
+        self.grid.clear_walls();
+        self.grid.clear_trails();
+        self.grid.stamp(&cells, margin, margin);
+        Ok(true)
+    }
+
+    /// Searches for a fill density whose random soups settle to about `target` live cells, give
+    /// or take `tolerance`, running at most `max_trials` trial soups.
+    ///
+    /// Each trial randomizes a copy of the game (with the same size, rule, walls, and edges) and
+    /// runs it until it dies out or repeats, or for up to 1000 generations, then bisects the
+    /// densities up to 0.5 on whether the population landed above or below the target. Every
+    /// trial uses the same seed, drawn from the game's generator, so only the density varies.
+    ///
+    /// The result is heuristic: settled populations are noisy and don't strictly grow with
+    /// density. Returns the density whose trial came closest if none landed within `tolerance`.
+    pub fn find_density_for_stable_population(
+        &mut self,
+        target: usize,
+        tolerance: usize,
+        max_trials: usize,
+    ) -> f32 {
+        let seed = self.rng.random();
+        let (mut low, mut high) = (0.0, MAX_SEARCH_DENSITY);
+        let mut best = (usize::MAX, 0.0);
+        for _ in 0..max_trials {
+            let density = (low + high) / 2.0;
+            let population = self.settled_population(density, seed);
+            let miss = population.abs_diff(target);
+            if miss < best.0 {
+                best = (miss, density);
+            }
+            if miss <= tolerance {
+                break;
+            }
+            if population < target {
+                low = density;
+            } else {
+                high = density;
+            }
+        }
+        best.1
+    }
+
+    /// Returns the population a copy of the game settles to after randomizing it with `density`
+    /// under `seed`.
+    fn settled_population(&self, density: f32, seed: u64) -> usize {
+        let mut trial = self.clone();
+        trial.rng = StdRng::seed_from_u64(seed);
+        trial.randomize(density);
+        for _ in 0..SETTLE_GENERATIONS {
+            if trial.grid.is_empty() || trial.period().is_some() {
+                break;
+            }
+            trial.step();
+        }
+        trial.grid.live_count()
     }
 
     /// Randomizes the grid with the specified density of alive cells (0.0 to 1.0).
@@ -102,13 +869,79 @@ impl Game {
             "Density must be within 0.0 to 1.0"
         );
 
-        use rand::Rng;
+        let mut rng = self.rng(None);
+        self.fill_with(|_, _| {
+            if rng.random::<f32>() < density {
+                CellState::Alive
+            } else {
+                CellState::Dead
+            }
+        });
+    }
+
+    /// Randomizes the grid with exactly `count` live cells, or as many as fit around the walls.
+    ///
+    /// Passing a `seed` makes the choice of cells reproducible.
+    pub fn randomize_count(&mut self, count: usize, seed: Option<u64>) {
+        let mut rng = self.rng(seed);
+        let (width, total_cells) = (self.grid.width(), self.grid.width() * self.grid.height());
+        let open: Vec<usize> = (0..total_cells)
+            .filter(|&idx| !self.grid.is_wall(idx / width, idx % width))
+            .collect();
+        let mut alive = vec![false; total_cells];
+        for i in index::sample(&mut rng, open.len(), count.min(open.len())) {
+            alive[open[i]] = true;
+        }
+        self.fill_with(|row, col| CellState::from_alive(alive[row * width + col]));
+    }
+
+    /// Randomizes the grid with a density of alive cells that varies linearly from `from` on one
+    /// side to `to` on the other (both 0.0 to 1.0).
+    ///
+    /// The density varies top to bottom if `vertical`, otherwise left to right. Passing a `seed`
+    /// makes the fill reproducible.
+    pub fn randomize_gradient(&mut self, from: f32, to: f32, vertical: bool, seed: Option<u64>) {
+        assert!(
+            (0.0..=1.0).contains(&from) && (0.0..=1.0).contains(&to),
+            "Density must be within 0.0 to 1.0"
+        );
+
+        let mut rng = self.rng(seed);
+        let steps = if vertical {
+            self.grid.height()
+        } else {
+            self.grid.width()
+        }
+        .saturating_sub(1)
+        .max(1);
+        self.fill_with(|row, col| {
+            let t = if vertical { row } else { col } as f32 / steps as f32;
+            CellState::from_alive(rng.random::<f32>() < from + (to - from) * t)
+        });
+    }
 
-        let mut rng = rand::rng();
+    /// Randomizes a rectangular region of the grid with the specified density of alive cells
+    /// (0.0 to 1.0), leaving the rest of the grid and the generation count untouched.
+    ///
+    /// The region is clipped to the grid's bounds.
+    pub fn randomize_region(
+        &mut self,
+        top: usize,
+        left: usize,
+        height: usize,
+        width: usize,
+        density: f32,
+    ) {
+        assert!(
+            (0.0..=1.0).contains(&density),
+            "Density must be within 0.0 to 1.0"
+        );
 
-        for row in 0..self.grid.height {
-            for col in 0..self.grid.width {
-                let state = if rng.random::<f32>() < density {
+        let bottom = top.saturating_add(height).min(self.grid.height());
+        let right = left.saturating_add(width).min(self.grid.width());
+        for row in top..bottom {
+            for col in left..right {
+                let state = if self.rng.random::<f32>() < density {
                     CellState::Alive
                 } else {
                     CellState::Dead
@@ -116,38 +949,1007 @@ impl Game {
                 self.grid.set(row, col, state);
             }
         }
+    }
+
+    /// Randomizes the cells inside a mask with the specified density of alive cells (0.0 to
+    /// 1.0), leaving the rest of the grid and the generation count untouched.
+    pub fn randomize_masked(&mut self, mask: &Mask, density: f32) {
+        assert!(
+            (0.0..=1.0).contains(&density),
+            "Density must be within 0.0 to 1.0"
+        );
+
+        for (row, col) in mask.iter() {
+            let alive = self.rng.random::<f32>() < density;
+            self.grid.set(row, col, CellState::from_alive(alive));
+        }
+    }
+
+    /// Toggles `n` distinct random cells, or every cell if `n` exceeds their number.
+    ///
+    /// Walls can't come alive, so they're never picked. Passing a `seed` makes the choice of
+    /// cells reproducible. The generation count is left untouched.
+    pub fn perturb(&mut self, n: usize, seed: Option<u64>) {
+        let mut rng = self.rng(seed);
+        let candidates: Vec<(usize, usize)> = self
+            .grid
+            .iter_cells()
+            .map(|(cell, _)| cell)
+            .filter(|&(row, col)| !self.grid.is_wall(row, col))
+            .collect();
+        for idx in index::sample(&mut rng, candidates.len(), n.min(candidates.len())) {
+            let (row, col) = candidates[idx];
+            let state = match self.grid.get(row, col) {
+                Some(CellState::Alive) => CellState::Dead,
+                _ => CellState::Alive,
+            };
+            self.grid.set(row, col, state);
+        }
+    }
+
+    /// Fills the grid with a checkerboard of alternating alive and dead cells.
+    pub fn fill_checkerboard(&mut self) {
+        self.fill_with(|row, col| {
+            if (row + col).is_multiple_of(2) {
+                CellState::Alive
+            } else {
+                CellState::Dead
+            }
+        });
+    }
+
+    /// Fills the grid with alternating alive and dead stripes, each `period` cells thick.
+    ///
+    /// Stripes run top to bottom if `vertical`, otherwise left to right.
+    pub fn fill_stripes(&mut self, period: usize, vertical: bool) {
+        assert!(period > 0, "Stripe period must be positive and non-zero");
+
+        self.fill_with(|row, col| {
+            let position = if vertical { col } else { row };
+            if (position / period).is_multiple_of(2) {
+                CellState::Alive
+            } else {
+                CellState::Dead
+            }
+        });
+    }
+
+    /// Returns a generator for one randomizing operation: seeded from `seed` if given, otherwise
+    /// drawn from the game's own generator.
+    fn rng(&mut self, seed: Option<u64>) -> StdRng {
+        match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut self.rng),
+        }
+    }
+
+    /// Sets every cell to the state returned by `state_at(row, col)` and resets stats.
+    fn fill_with(&mut self, mut state_at: impl FnMut(usize, usize) -> CellState) {
+        self.grid.set_log_generation(0);
+        for row in 0..self.grid.height() {
+            for col in 0..self.grid.width() {
+                self.grid.set(row, col, state_at(row, col));
+            }
+        }
+        self.grid.clear_trails();
         self.generation = 0;
+        self.last_step = StepStats::default();
+        self.reset_run_stats();
+    }
+
+    /// Sets the tick interval, clamped to the bounds set by [`Game::set_interval_bounds`].
+    ///
+    /// Returns the interval actually set.
+    pub fn set_interval(&mut self, interval: Duration) -> Duration {
+        self.tick_interval = interval.clamp(self.min_interval, self.max_interval);
+        self.tick_interval
     }
 
     /// Increases the tick interval (slows down the simulation).
     pub fn inc_interval(&mut self) -> Duration {
-        self.tick_interval = self
-            .tick_interval
-            .saturating_add(INTERVAL_STEP)
-            .min(MAX_INTERVAL);
-        self.tick_interval
+        self.set_interval(self.tick_interval.saturating_add(self.interval_step))
     }
 
     /// Decreases the tick intierval (speeds up the simulation).
     pub fn dec_interval(&mut self) -> Duration {
-        self.tick_interval = self
-            .tick_interval
-            .saturating_sub(INTERVAL_STEP)
-            .max(MIN_INTERVAL);
-        self.tick_interval
+        self.set_interval(self.tick_interval.saturating_sub(self.interval_step))
+    }
+
+    /// Returns the tick interval of the speed preset at `index` in [`SPEED_PRESETS`], or `None`
+    /// if there's no such preset.
+    ///
+    /// The slowest and fastest presets are the longest and shortest intervals allowed by the
+    /// current bounds, and the others are clamped to them.
+    pub fn preset_interval(&self, index: usize) -> Option<Duration> {
+        let &(_, interval) = SPEED_PRESETS.get(index)?;
+        Some(match index {
+            0 => self.max_interval,
+            _ if index == SPEED_PRESETS.len() - 1 => self.min_interval,
+            _ => interval.clamp(self.min_interval, self.max_interval),
+        })
+    }
+
+    /// Sets the tick interval to the speed preset at `index` in [`SPEED_PRESETS`].
+    ///
+    /// Returns the interval set, or `None` if there's no such preset.
+    pub fn set_speed_preset(&mut self, index: usize) -> Option<Duration> {
+        let interval = self.preset_interval(index)?;
+        Some(self.set_interval(interval))
+    }
+
+    /// Returns the (min, max, step) that speed adjustments are limited to, in that order.
+    pub fn interval_bounds(&self) -> (Duration, Duration, Duration) {
+        (self.min_interval, self.max_interval, self.interval_step)
+    }
+
+    /// Sets the shortest and longest tick intervals reachable by speed adjustments, and the
+    /// amount each adjustment changes the interval by.
+    ///
+    /// The current interval is left as is, even if it's outside the new bounds. Returns a message
+    /// describing the problem if `min` isn't below `max` or `step` is zero.
+    pub fn set_interval_bounds(
+        &mut self,
+        min: Duration,
+        max: Duration,
+        step: Duration,
+    ) -> Result<(), String> {
+        if min >= max {
+            return Err("minimum interval must be below the maximum".to_string());
+        }
+        if step.is_zero() {
+            return Err("interval step must be non-zero".to_string());
+        }
+        self.min_interval = min;
+        self.max_interval = max;
+        self.interval_step = step;
+        Ok(())
+    }
+
+    /// Returns the name of the speed preset matching the tick interval, if any.
+    pub fn speed_preset(&self) -> Option<&'static str> {
+        (0..SPEED_PRESETS.len())
+            .find(|&index| self.preset_interval(index) == Some(self.tick_interval))
+            .map(|index| SPEED_PRESETS[index].0)
     }
 
     /// Toggles between running and paused states.
     pub fn toggle_pause(&mut self) {
         self.state = if self.state == GameState::Running {
+            if let Some(resumed_at) = self.resumed_at.take() {
+                self.run_time += resumed_at.elapsed();
+            }
             GameState::Paused
         } else {
+            self.resumed_at = Some(Instant::now());
             GameState::Running
         };
     }
 
+    /// Returns how long the simulation has been running since the grid was last reset, not
+    /// counting time spent paused.
+    pub fn running_time(&self) -> Duration {
+        self.run_time + self.resumed_at.map_or(Duration::ZERO, |at| at.elapsed())
+    }
+
+    /// Returns the average generations per second of running time since the grid was last
+    /// reset, or `None` if it hasn't run yet.
+    pub fn average_rate(&self) -> Option<f64> {
+        let seconds = self.running_time().as_secs_f64();
+        let generations = self.generation.saturating_sub(self.run_start_generation);
+        (seconds > 0.0).then(|| generations as f64 / seconds)
+    }
+
+    /// Restarts the running time, average rate, and history from the current generation, and
+    /// forgets the reversible rule's previous generation.
+    fn reset_run_stats(&mut self) {
+        self.history.clear();
+        self.recent_hashes.clear();
+        self.recent_shapes.clear();
+        self.stabilized_at = None;
+        self.previous.clear();
+        self.run_time = Duration::ZERO;
+        self.resumed_at = (!self.is_paused()).then(Instant::now);
+        self.run_start_generation = self.generation;
+        self.grid.set_log_generation(self.generation);
+    }
+
+    /// Returns the number of generations after which the grid has repeated itself exactly, if
+    /// it has within the last 64 generations (`1` for a still life).
+    ///
+    /// Returns `None` for an empty grid, or if the grid was edited since the last step.
+    pub fn period(&self) -> Option<u64> {
+        let current = self.grid.state_hash();
+        if self.grid.is_empty() || self.recent_hashes.back() != Some(&current) {
+            return None;
+        }
+        self.recent_hashes
+            .iter()
+            .rev()
+            .skip(1)
+            .position(|&hash| hash == current)
+            .map(|distance| distance as u64 + 1)
+    }
+
+    /// Detects a spaceship: live cells that have reappeared within the last 64 generations
+    /// shifted to a new position.
+    ///
+    /// Returns the period and the displacement over one period as (rows, cols), positive
+    /// downwards and rightwards. Returns `None` for an empty grid, a grid that repeats in place,
+    /// or if the grid was edited since the last step. Cells are compared by their bounding box
+    /// like [`Grid::fingerprint`], so a spaceship isn't recognized while it straddles an edge
+    /// that wraps. Displacements are taken the short way around with [`Grid::wrapped_delta`], so
+    /// one that has just crossed such an edge still reports its true heading.
+    pub fn detect_spaceship(&self) -> Option<(u32, (i32, i32))> {
+        if self.grid.is_empty() || self.recent_hashes.back() != Some(&self.grid.state_hash()) {
+            return None;
+        }
+        let &(current, (top, left)) = self.recent_shapes.back()?;
+        let (distance, &(_, (old_top, old_left))) = self
+            .recent_shapes
+            .iter()
+            .rev()
+            .skip(1)
+            .enumerate()
+            .find(|(_, (hash, _))| *hash == current)?;
+
+        let (rows, cols) = self.grid.wrapped_delta((old_top, old_left), (top, left));
+        let (rows, cols) = (rows as i32, cols as i32);
+        if (rows, cols) == (0, 0) {
+            return None;
+        }
+        Some((distance as u32 + 1, (rows, cols)))
+    }
+
+    /// Returns the generation from which the grid has been repeating its current period, if one
+    /// is detected.
+    pub fn stabilized_at(&self) -> Option<u64> {
+        self.period().and(self.stabilized_at)
+    }
+
+    /// Returns how many generations it took the grid to settle into its current period since it
+    /// was last reset, if one is detected.
+    pub fn generations_to_stabilize(&self) -> Option<u64> {
+        self.stabilized_at()
+            .map(|generation| generation.saturating_sub(self.run_start_generation))
+    }
+
+    /// Returns true if a run target is set and the current generation has reached it.
+    pub fn reached_run_target(&self) -> bool {
+        self.run_target
+            .is_some_and(|target| self.generation >= target)
+    }
+
     /// Returns true if the simulation is currently paused.
     pub fn is_paused(&self) -> bool {
         self.state == GameState::Paused
     }
 }
+
+/// Error returned when decoding a game with [`Game::from_bytes`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The data doesn't start with the format's magic bytes.
+    BadMagic,
+    /// The data was written by an unsupported version of the format.
+    UnsupportedVersion(u8),
+    /// The data ends before all the cells.
+    Truncated,
+    /// Extra data follows the cells, or live cells are marked past the last one.
+    TrailingBytes,
+    /// The rule isn't valid B/S notation.
+    Rule(ParseRuleError),
+    /// The grid dimensions aren't valid.
+    Grid(GridError),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::BadMagic => write!(f, "not a ratgol binary board"),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {version}")
+            }
+            DecodeError::Truncated => write!(f, "data ends early"),
+            DecodeError::TrailingBytes => write!(f, "unexpected data after the cells"),
+            DecodeError::Rule(e) => write!(f, "invalid rule: {e}"),
+            DecodeError::Grid(e) => write!(f, "invalid grid: {e}"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Serializes the board, rule, and simulation settings.
+///
+/// Step statistics, population history, period detection, and running time aren't saved; they
+/// start over when the game is deserialized.
+#[cfg(feature = "serde")]
+impl Serialize for Game {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Game", 7)?;
+        state.serialize_field("grid", &self.grid)?;
+        state.serialize_field("rule", &self.rule)?;
+        state.serialize_field("state", &self.state)?;
+        state.serialize_field("tick_interval", &self.tick_interval)?;
+        state.serialize_field("generation", &self.generation)?;
+        state.serialize_field("run_target", &self.run_target)?;
+        state.serialize_field("pause_after", &self.pause_after)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Game {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Game")]
+        struct Fields {
+            grid: Grid,
+            rule: Rule,
+            state: GameState,
+            tick_interval: Duration,
+            generation: u64,
+            run_target: Option<u64>,
+            #[serde(default)]
+            pause_after: Option<u64>,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+        Ok(Self {
+            grid: fields.grid,
+
+            rule: fields.rule,
+            state: fields.state,
+            tick_interval: fields.tick_interval,
+            min_interval: MIN_INTERVAL,
+            max_interval: MAX_INTERVAL,
+            interval_step: INTERVAL_STEP,
+            generation: fields.generation,
+            last_step: StepStats::default(),
+            run_target: fields.run_target,
+            pause_after: fields.pause_after,
+            history: PopulationHistory::default(),
+            recent_hashes: VecDeque::with_capacity(PERIOD_WINDOW + 1),
+            recent_shapes: VecDeque::with_capacity(PERIOD_WINDOW + 1),
+            stabilized_at: None,
+
+            run_time: Duration::ZERO,
+            resumed_at: (fields.state == GameState::Running).then(Instant::now),
+            run_start_generation: fields.generation,
+            stepped_rule: fields.rule,
+            reversible: false,
+            previous: Vec::new(),
+            rng: StdRng::from_os_rng(),
+            on_step: OnStep::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Topology;
+
+    use super::*;
+
+    /// Builds a game with a reproducible random soup.
+    fn seeded_game(width: usize, height: usize, seed: u64) -> Game {
+        let mut game = Game::with_rng((width, height), StdRng::seed_from_u64(seed));
+        game.randomize(0.4);
+        game
+    }
+
+    /// Steps `game` normally, which only revisits cells around the last generation's changes,
+    /// alongside a copy forced to scan every cell, checking they agree after each generation.
+    fn assert_steps_match_full_scan(mut game: Game, generations: u64) {
+        let mut full_scan = game.clone();
+        for generation in 1..=generations {
+            game.step();
+            full_scan.grid.mark_all_changed();
+            full_scan.step();
+            assert!(
+                game.grid == full_scan.grid,
+                "generation {generation}: stepped\n{}\nfull scan\n{}",
+                game.grid.render(),
+                full_scan.grid.render()
+            );
+        }
+    }
+
+    #[test]
+    fn step_matches_full_scan_on_random_soups() {
+        let rules = ["B3/S23", "B36/S23", "B2/S", "B3678/S34678"];
+        for topology in [Topology::Torus, Topology::Cylinder, Topology::Bounded] {
+            for (seed, rule) in rules.iter().enumerate() {
+                let mut game = seeded_game(32, 24, seed as u64);
+                game.rule = Rule::parse(rule).unwrap();
+                game.grid.set_topology(topology);
+                assert_steps_match_full_scan(game, 200);
+            }
+        }
+    }
+
+    #[test]
+    fn step_matches_full_scan_with_walls() {
+        for walls_alive in [false, true] {
+            let mut game = seeded_game(40, 30, 352);
+            // A wall across the board and a few along its edges
+            for col in 5..35 {
+                game.grid.set(15, col, CellState::Dead);
+                game.grid.set_wall(15, col, true);
+            }
+            for (row, col) in [(0, 0), (0, 39), (29, 0), (29, 39), (10, 0), (0, 20)] {
+                game.grid.set(row, col, CellState::Dead);
+                game.grid.set_wall(row, col, true);
+            }
+            game.grid.set_walls_alive(walls_alive);
+            assert_steps_match_full_scan(game, 300);
+        }
+    }
+
+    #[test]
+    fn step_matches_full_scan_when_gliders_reach_still_lifes() {
+        // Gliders heading across the wrapped edges into blocks that haven't changed for many
+        // generations
+        let glider = patterns::find("glider").unwrap().cells;
+        let block = patterns::find("block").unwrap().cells;
+        let mut game = Game::new((48, 32));
+        game.clear();
+        game.grid.stamp(glider, 2, 2);
+        game.grid.stamp(glider, 20, 40);
+        for (row, col) in [(14, 14), (28, 6), (8, 30), (24, 24)] {
+            game.grid.stamp(block, row, col);
+        }
+        assert_steps_match_full_scan(game, 400);
+    }
+
+    #[test]
+    fn perturb_toggles_exactly_n_distinct_cells() {
+        for (n, walls) in [(0, 0), (1, 0), (25, 0), (25, 40), (64, 0), (200, 20)] {
+            let mut game = seeded_game(10, 8, n as u64);
+            for idx in 0..walls {
+                game.grid.set(idx / 10, idx % 10, CellState::Dead);
+                game.grid.set_wall(idx / 10, idx % 10, true);
+            }
+            let before = game.grid.clone();
+
+            game.perturb(n, Some(347));
+            let changed: Vec<_> = before
+                .iter_cells()
+                .filter(|&((row, col), state)| game.grid.get(row, col) != Some(state))
+                .collect();
+            assert_eq!(changed.len(), n.min(80 - walls), "n = {n}, walls = {walls}");
+            assert!(
+                changed
+                    .iter()
+                    .all(|&((row, col), _)| !game.grid.is_wall(row, col))
+            );
+
+            let born = changed
+                .iter()
+                .filter(|(_, state)| !state.is_alive())
+                .count();
+            let died = changed.len() - born;
+            assert_eq!(
+                game.grid.live_count(),
+                before.live_count() + born - died,
+                "n = {n}, walls = {walls}"
+            );
+        }
+    }
+
+    #[test]
+    fn perturb_with_a_seed_is_reproducible() {
+        let mut first = seeded_game(16, 16, 1);
+        let mut second = first.clone();
+        first.perturb(30, Some(9));
+        second.perturb(30, Some(9));
+        assert!(first.grid == second.grid);
+    }
+
+    #[test]
+    fn speed_presets_match_the_defaults_under_default_bounds() {
+        let mut game = Game::new((8, 8));
+        for (index, &(name, interval)) in SPEED_PRESETS.iter().enumerate() {
+            assert_eq!(game.set_speed_preset(index), Some(interval));
+            assert_eq!(game.speed_preset(), Some(name));
+        }
+        assert_eq!(game.set_speed_preset(SPEED_PRESETS.len()), None);
+    }
+
+    #[test]
+    fn speed_presets_stay_within_configured_bounds() {
+        let mut game = Game::new((8, 8));
+        let (min, max) = (Duration::from_millis(150), Duration::from_millis(400));
+        game.set_interval_bounds(min, max, Duration::from_millis(10))
+            .unwrap();
+
+        assert_eq!(game.set_speed_preset(0), Some(max));
+        assert_eq!(game.speed_preset(), Some("slowest"));
+        assert_eq!(game.set_speed_preset(1), Some(Duration::from_millis(400)));
+        assert_eq!(game.set_speed_preset(2), Some(Duration::from_millis(200)));
+        assert_eq!(game.set_speed_preset(3), Some(min));
+        assert_eq!(game.set_speed_preset(4), Some(min));
+        for index in 0..SPEED_PRESETS.len() {
+            let interval = game.set_speed_preset(index).unwrap();
+            assert!(
+                (min..=max).contains(&interval),
+                "preset {index}: {interval:?}"
+            );
+            assert_eq!(game.tick_interval, interval);
+        }
+
+        // Speeding up from the fastest preset goes no further
+        game.set_speed_preset(4);
+        assert_eq!(game.dec_interval(), min);
+    }
+
+    #[test]
+    fn binary_round_trip_keeps_board_generation_and_rule() {
+        // Widths that aren't a multiple of 8 put row boundaries in the middle of bytes
+        for (width, height) in [(8, 8), (13, 7), (1, 1), (3, 50), (64, 33)] {
+            let mut game = seeded_game(width, height, 417);
+            game.rule = Rule::parse("B36/S23").unwrap();
+            for _ in 0..5 {
+                game.step();
+            }
+
+            let bytes = game.to_bytes();
+            let decoded = Game::from_bytes(&bytes).unwrap();
+            assert!(decoded.grid == game.grid, "{width}×{height}");
+            assert_eq!(decoded.grid.live_count(), game.grid.live_count());
+            assert_eq!(decoded.generation, game.generation);
+            assert_eq!(decoded.rule, game.rule);
+            assert_eq!(decoded.to_bytes(), bytes);
+        }
+    }
+
+    #[test]
+    fn binary_packs_cells_from_the_lowest_bit() {
+        let mut game = Game::new((10, 1));
+        game.grid.set(0, 0, CellState::Alive);
+        game.grid.set(0, 9, CellState::Alive);
+        let bytes = game.to_bytes();
+        assert_eq!(bytes.len(), 4 + 1 + 4 + 4 + 8 + 1 + "B3/S23".len() + 2);
+        assert_eq!(&bytes[bytes.len() - 2..], &[0b0000_0001, 0b0000_0010]);
+    }
+
+    #[test]
+    fn binary_rejects_bad_magic() {
+        let mut bytes = seeded_game(9, 9, 1).to_bytes();
+        bytes[0] = b'X';
+        assert_eq!(Game::from_bytes(&bytes).unwrap_err(), DecodeError::BadMagic);
+        assert_eq!(Game::from_bytes(b"").unwrap_err(), DecodeError::Truncated);
+    }
+
+    #[test]
+    fn binary_rejects_other_versions() {
+        let mut bytes = seeded_game(9, 9, 2).to_bytes();
+        bytes[4] = BINARY_VERSION + 1;
+        assert_eq!(
+            Game::from_bytes(&bytes).unwrap_err(),
+            DecodeError::UnsupportedVersion(BINARY_VERSION + 1)
+        );
+    }
+
+    #[test]
+    fn binary_rejects_truncated_and_padded_input() {
+        let bytes = seeded_game(13, 5, 3).to_bytes();
+        for len in 0..bytes.len() {
+            assert_eq!(
+                Game::from_bytes(&bytes[..len]).unwrap_err(),
+                DecodeError::Truncated,
+                "first {len} of {} bytes",
+                bytes.len()
+            );
+        }
+        let mut padded = bytes.clone();
+        padded.push(0);
+        assert_eq!(
+            Game::from_bytes(&padded).unwrap_err(),
+            DecodeError::TrailingBytes
+        );
+    }
+
+    #[test]
+    fn restoring_a_snapshot_undoes_everything_since() {
+        let mut game = seeded_game(20, 15, 355);
+        for _ in 0..3 {
+            game.step();
+        }
+        let snapshot = game.snapshot();
+        let mut unchanged = game.clone();
+
+        for _ in 0..10 {
+            game.step();
+        }
+        game.perturb(12, Some(355));
+        game.grid.set_wall(0, 0, true);
+        game.resize(30, 25);
+        assert!(game.grid != unchanged.grid);
+
+        game.restore(&snapshot);
+        assert!(game.grid == unchanged.grid);
+        assert_eq!(game.grid.dimensions(), (20, 15));
+        assert!(!game.grid.is_wall(0, 0));
+        assert_eq!(game.generation, 3);
+        assert_eq!(snapshot.generation(), 3);
+        assert!(snapshot.grid() == &unchanged.grid);
+
+        // The restored board carries on exactly as the original would have
+        for _ in 0..20 {
+            game.step();
+            unchanged.step();
+        }
+        assert!(game.grid == unchanged.grid);
+        assert_eq!(game.generation, unchanged.generation);
+    }
+
+    #[test]
+    fn fit_to_pattern_fails_without_touching_the_grid_when_too_large() {
+        let mut game = Game::new((20, 20));
+        game.grid
+            .stamp(&[(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)], 5, 7);
+        let before = game.grid.clone();
+        for margin in [100_000, usize::MAX / 2, usize::MAX] {
+            assert!(matches!(
+                game.fit_to_pattern(margin),
+                Err(GridError::TooLarge { .. })
+            ));
+            assert!(game.grid == before);
+        }
+
+        assert_eq!(game.fit_to_pattern(2), Ok(true));
+        assert_eq!(game.grid.dimensions(), (7, 7));
+        assert_eq!(game.grid.live_count(), 5);
+        assert_eq!(Game::new((4, 4)).fit_to_pattern(2), Ok(false));
+    }
+
+    #[test]
+    fn load_scene_skips_entries_it_cant_place() {
+        let scene = Scene::parse(
+            "# unknown patterns and placements past, or overflowing, the edges are skipped\n\
+             glider, 1, 1, 0\n\
+             no-such-pattern, 5, 5, 0\n\
+             block, 19, 19, 0\n\
+             glider, 18446744073709551615, 0, 0\n\
+             blinker, 0, 18446744073709551615, 90\n\
+             block, 10, 10, 0",
+        )
+        .unwrap();
+        let mut game = Game::new((20, 20));
+        let errors = game.load_scene(&scene);
+        assert_eq!(errors.len(), 4, "{errors:?}");
+        assert_eq!(errors[0], "line 3: unknown pattern: no-such-pattern");
+        assert!(errors[1].starts_with("line 4: block at (19, 19)"));
+        for (error, line) in errors[1..].iter().zip(4..) {
+            assert!(error.starts_with(&format!("line {line}: ")), "{error}");
+            assert!(error.ends_with("is out of bounds"), "{error}");
+        }
+        // The glider and the last block are still placed
+        assert_eq!(game.grid.live_count(), 5 + 4);
+    }
+
+    #[test]
+    fn clear_outside_keeps_only_the_region() {
+        // Regions inside the grid and hanging off its bottom-right corner, on a full board and
+        // on a random soup
+        for (top, left, height, width) in [(3, 4, 5, 6), (14, 20, 10, 10)] {
+            let mut full = Game::with_rng((24, 18), StdRng::seed_from_u64(371));
+            full.randomize(1.0);
+            for mut game in [full, seeded_game(24, 18, 371)] {
+                let inside = |row: usize, col: usize| {
+                    (top..top + height).contains(&row) && (left..left + width).contains(&col)
+                };
+                let before = game.grid.clone();
+                let inside_count = before
+                    .iter_alive_cells()
+                    .filter(|&(row, col)| inside(row, col))
+                    .count();
+                game.clear_outside(top, left, height, width);
+                for ((row, col), state) in game.grid.iter_cells() {
+                    if inside(row, col) {
+                        assert_eq!(Some(state), before.get(row, col), "({row}, {col})");
+                    } else {
+                        assert_eq!(state, CellState::Dead, "({row}, {col})");
+                    }
+                }
+                assert_eq!(game.grid.live_count(), inside_count);
+            }
+        }
+    }
+
+    #[test]
+    fn step_region_never_changes_cells_outside_it() {
+        // Regions inside the grid, hanging off its bottom-right corner, and sized so their far
+        // edges would overflow
+        let regions: [(usize, usize, usize, usize); 4] = [
+            (5, 6, 8, 9),
+            (14, 20, 10, 10),
+            (1, 0, usize::MAX, 4),
+            (3, 2, 6, usize::MAX),
+        ];
+        for (top, left, height, width) in regions {
+            let mut game = seeded_game(24, 18, 340);
+            let inside = |row: usize, col: usize| {
+                (top..top.saturating_add(height)).contains(&row)
+                    && (left..left.saturating_add(width)).contains(&col)
+            };
+            let mut changed_inside = false;
+            for _ in 0..30 {
+                let before = game.clone();
+                let mut full = game.clone();
+                full.step();
+                game.step_region(top, left, height, width);
+                for ((row, col), state) in game.grid.iter_cells() {
+                    if inside(row, col) {
+                        // Inside, cells follow the rule, counting the frozen cells around them
+                        assert_eq!(Some(state), full.grid.get(row, col));
+                        changed_inside |= Some(state) != before.grid.get(row, col);
+                    } else {
+                        assert_eq!(Some(state), before.grid.get(row, col), "({row}, {col})");
+                    }
+                }
+            }
+            assert!(changed_inside);
+        }
+    }
+
+    #[test]
+    fn randomize_region_clips_regions_whose_far_edges_would_overflow() {
+        for (height, width) in [(usize::MAX, 4), (6, usize::MAX), (usize::MAX, usize::MAX)] {
+            let mut game = Game::with_rng((24, 18), StdRng::seed_from_u64(338));
+            game.randomize_region(3, 2, height, width, 1.0);
+            for ((row, col), state) in game.grid.iter_cells() {
+                let inside = row >= 3 && row - 3 < height && col >= 2 && col - 2 < width;
+                assert_eq!(state, CellState::from_alive(inside), "({row}, {col})");
+            }
+        }
+    }
+
+    #[test]
+    fn step_masked_never_changes_cells_outside_the_mask() {
+        let mut game = seeded_game(24, 18, 398);
+        // A disk hanging off the left edge, plus a scattering of single cells
+        let mut mask = Mask::disk(24, 18, 9, 2, 6);
+        for (row, col) in [(0, 20), (3, 17), (17, 23), (12, 12)] {
+            mask.set(row, col, true);
+        }
+        let mut changed_inside = false;
+        for _ in 0..30 {
+            let before = game.clone();
+            let mut full = game.clone();
+            full.step();
+            game.step_masked(&mask);
+            for ((row, col), state) in game.grid.iter_cells() {
+                if mask.contains(row, col) {
+                    assert_eq!(Some(state), full.grid.get(row, col));
+                    changed_inside |= Some(state) != before.grid.get(row, col);
+                } else {
+                    assert_eq!(Some(state), before.grid.get(row, col), "({row}, {col})");
+                }
+            }
+        }
+        assert!(changed_inside);
+
+        // A mask larger than the grid only steps the cells the grid has
+        let mut game = seeded_game(8, 8, 398);
+        let mut mask = Mask::new(12, 12);
+        mask.set(10, 10, true);
+        let before = game.grid.clone();
+        game.step_masked(&mask);
+        assert!(game.grid == before);
+    }
+
+    /// Returns the number of cells inside `mask` that differ between `before` and `after`,
+    /// checking that none outside it do.
+    fn changes_inside_mask(before: &Grid, after: &Grid, mask: &Mask) -> usize {
+        let mut changed = 0;
+        for ((row, col), state) in after.iter_cells() {
+            if mask.contains(row, col) {
+                changed += usize::from(Some(state) != before.get(row, col));
+            } else {
+                assert_eq!(Some(state), before.get(row, col), "({row}, {col})");
+            }
+        }
+        changed
+    }
+
+    #[test]
+    fn clear_masked_kills_only_the_cells_inside_the_mask() {
+        // On a full board and on a random soup
+        let mut full = Game::with_rng((24, 18), StdRng::seed_from_u64(398));
+        full.randomize(1.0);
+        for mut game in [full, seeded_game(24, 18, 398)] {
+            let mask = Mask::disk(24, 18, 9, 2, 6);
+            let before = game.grid.clone();
+            game.clear_masked(&mask);
+            let changed = changes_inside_mask(&before, &game.grid, &mask);
+            assert!(
+                mask.iter()
+                    .all(|(row, col)| game.grid.get(row, col) == Some(CellState::Dead))
+            );
+            assert_eq!(before.live_count() - game.grid.live_count(), changed);
+        }
+    }
+
+    #[test]
+    fn randomize_masked_only_fills_the_cells_inside_the_mask() {
+        let mut game = Game::with_rng((24, 18), StdRng::seed_from_u64(398));
+        let mut mask = Mask::disk(24, 18, 9, 2, 6);
+        mask.set(17, 23, true);
+        let before = game.grid.clone();
+        game.randomize_masked(&mask, 0.5);
+        let changed = changes_inside_mask(&before, &game.grid, &mask);
+        assert!(changed > 0 && changed < mask.count());
+        assert_eq!(game.grid.live_count(), changed);
+
+        // On a soup, the population moves by the births minus the deaths inside the mask
+        let mut game = seeded_game(24, 18, 398);
+        let before = game.grid.clone();
+        game.randomize_masked(&mask, 0.5);
+        changes_inside_mask(&before, &game.grid, &mask);
+        let (born, died) = mask.iter().fold((0, 0), |(born, died), (row, col)| {
+            let alive = |grid: &Grid| grid.get(row, col) == Some(CellState::Alive);
+            match (alive(&before), alive(&game.grid)) {
+                (false, true) => (born + 1, died),
+                (true, false) => (born, died + 1),
+                _ => (born, died),
+            }
+        });
+        assert_eq!(game.grid.live_count() + died, before.live_count() + born);
+    }
+
+    #[test]
+    fn randomize_count_places_exactly_that_many_cells() {
+        let mut game = seeded_game(20, 15, 386);
+        for count in [0, 1, 7, 150, 299, 300] {
+            game.randomize_count(count, None);
+            assert_eq!(game.grid.live_count(), count);
+        }
+        // No more than fit
+        game.randomize_count(1000, None);
+        assert_eq!(game.grid.live_count(), 300);
+
+        // Or than fit around the walls, which stay dead
+        for col in 0..20 {
+            game.grid.set_wall(7, col, true);
+        }
+        game.randomize_count(100, Some(386));
+        assert_eq!(game.grid.live_count(), 100);
+        assert!((0..20).all(|col| game.grid.get(7, col) != Some(CellState::Alive)));
+        game.randomize_count(300, None);
+        assert_eq!(game.grid.live_count(), 280);
+
+        // The same seed picks the same cells
+        let mut other = seeded_game(20, 15, 0);
+        for col in 0..20 {
+            other.grid.set_wall(7, col, true);
+        }
+        game.randomize_count(100, Some(386));
+        other.randomize_count(100, Some(386));
+        assert!(game.grid == other.grid);
+    }
+
+    #[test]
+    fn randomize_gradient_follows_the_requested_densities() {
+        let (width, height) = (400, 50);
+        let mut game = Game::with_rng((width, height), StdRng::seed_from_u64(391));
+        let density = |game: &Game, cells: &mut dyn Iterator<Item = (usize, usize)>| {
+            let (mut alive, mut total) = (0, 0);
+            for (row, col) in cells {
+                alive += usize::from(game.grid.get(row, col) == Some(CellState::Alive));
+                total += 1;
+            }
+            alive as f32 / total as f32
+        };
+
+        game.randomize_gradient(0.1, 0.9, true, None);
+        for row in 0..height {
+            let expected = 0.1 + 0.8 * row as f32 / (height - 1) as f32;
+            let actual = density(&game, &mut (0..width).map(|col| (row, col)));
+            // Four standard deviations of a row's density at worst
+            assert!(
+                (actual - expected).abs() < 0.1,
+                "row {row}: {actual} vs {expected}"
+            );
+        }
+        let top = density(&game, &mut (0..width).map(|col| (0, col)));
+        let bottom = density(&game, &mut (0..width).map(|col| (height - 1, col)));
+        assert!(top < 0.2 && bottom > 0.8, "{top} to {bottom}");
+
+        // Left to right, and falling instead of rising
+        let mut game = Game::with_rng((50, 400), StdRng::seed_from_u64(391));
+        game.randomize_gradient(0.75, 0.0, false, None);
+        for col in 0..50 {
+            let expected = 0.75 - 0.75 * col as f32 / 49.0;
+            let actual = density(&game, &mut (0..400).map(|row| (row, col)));
+            assert!(
+                (actual - expected).abs() < 0.1,
+                "col {col}: {actual} vs {expected}"
+            );
+        }
+        assert!((0..400).all(|row| game.grid.get(row, 49) == Some(CellState::Dead)));
+    }
+
+    #[test]
+    fn center_of_a_full_block_counts_itself_only_when_the_rule_does() {
+        let mut game = Game::new((5, 5));
+        game.grid.stamp(
+            &[
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (1, 0),
+                (1, 1),
+                (1, 2),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+            ],
+            1,
+            1,
+        );
+
+        game.rule = Rule::parse("B3/S23").unwrap();
+        assert_eq!(game.rule_count(2, 2), 8);
+        game.rule = Rule::parse("B3/S23/T").unwrap();
+        assert_eq!(game.rule_count(2, 2), 9);
+
+        // A dead cell adds nothing either way
+        game.grid.set(2, 2, CellState::Dead);
+        assert_eq!(game.rule_count(2, 2), 8);
+        game.rule = Rule::parse("B3/S23").unwrap();
+        assert_eq!(game.rule_count(2, 2), 8);
+
+        // Only a full neighborhood reaches 9, so it alone keeps the center alive under S9
+        game.grid.set(2, 2, CellState::Alive);
+        game.rule = Rule::parse("B/S9/T").unwrap();
+        game.step();
+        assert_eq!(game.grid.iter_alive_cells().collect::<Vec<_>>(), [(2, 2)]);
+    }
+
+    /// Draws the grid one row per string, `#` for live cells and `.` for dead ones.
+    fn cell_rows(game: &Game) -> Vec<String> {
+        let (width, height) = game.grid.dimensions();
+        (0..height)
+            .map(|row| {
+                (0..width)
+                    .map(|col| match game.grid.get(row, col) {
+                        Some(CellState::Alive) => '#',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fill_checkerboard_alternates_from_a_live_corner() {
+        let mut game = seeded_game(5, 4, 1);
+        game.step();
+        game.fill_checkerboard();
+        assert_eq!(cell_rows(&game), ["#.#.#", ".#.#.", "#.#.#", ".#.#."]);
+        assert_eq!(game.grid.live_count(), 10);
+        assert_eq!(game.generation, 0);
+    }
+
+    #[test]
+    fn fill_stripes_runs_the_chosen_way() {
+        let mut game = seeded_game(6, 5, 1);
+
+        game.fill_stripes(2, false);
+        assert_eq!(
+            cell_rows(&game),
+            ["######", "######", "......", "......", "######"]
+        );
+        assert_eq!(game.grid.live_count(), 18);
+
+        game.fill_stripes(2, true);
+        assert_eq!(cell_rows(&game), ["##..##"; 5]);
+        assert_eq!(game.grid.live_count(), 20);
+
+        game.fill_stripes(1, true);
+        assert_eq!(cell_rows(&game), ["#.#.#."; 5]);
+        assert_eq!(game.grid.live_count(), 15);
+    }
+}
@@ -1,7 +1,11 @@
-use std::fmt;
+use std::{error::Error, fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser::SerializeStruct};
 
 /// State of a cell.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CellState {
     Dead,
     Alive,
@@ -12,9 +16,108 @@ impl CellState {
     pub fn is_alive(&self) -> bool {
         matches!(self, CellState::Alive)
     }
+
+    /// Returns the live state if `alive`, otherwise the dead state
+    pub fn from_alive(alive: bool) -> Self {
+        if alive {
+            CellState::Alive
+        } else {
+            CellState::Dead
+        }
+    }
 }
 
-/// A 2D grid for Conway's Game of Life with toroidal topology
+/// First codepoint of the Unicode Braille Patterns block (no dots raised).
+pub const BRAILLE_BASE: u32 = 0x2800;
+
+/// Most cells [`Grid::try_new`] and [`Grid::resize`] will allocate, 4096×4096.
+///
+/// Each cell takes several bytes across the grid's per-cell buffers, so this keeps a grid to a
+/// few hundred megabytes; use [`Grid::try_new_with_limit`] for a different limit.
+pub const DEFAULT_MAX_CELLS: usize = 1 << 24;
+
+/// Shade glyphs for blocks of cells, from empty to full.
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+/// Shape of the area covered by a brush.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BrushShape {
+    /// Every cell within `radius` rows and columns (Chebyshev distance).
+    #[default]
+    Square,
+    /// Every cell within `radius` straight-line distance (Euclidean distance).
+    Disk,
+}
+
+impl FromStr for BrushShape {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "square" => Ok(BrushShape::Square),
+            "disk" => Ok(BrushShape::Disk),
+            _ => Err(format!("unknown brush shape: {s}")),
+        }
+    }
+}
+
+/// Named combinations of wrapping along each axis, deciding which cells neighbor those along
+/// a grid's edges.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Topology {
+    /// Opposite edges are joined, so patterns leaving one side come back on the other.
+    #[default]
+    Torus,
+    /// The left and right edges are joined; everything above and below is permanently dead.
+    Cylinder,
+    /// Everything beyond the edges is permanently dead.
+    Bounded,
+}
+
+impl Topology {
+    /// Returns whether the topology wraps horizontally and vertically, in that order.
+    pub fn wraps(self) -> (bool, bool) {
+        match self {
+            Topology::Torus => (true, true),
+            Topology::Cylinder => (true, false),
+            Topology::Bounded => (false, false),
+        }
+    }
+}
+
+impl FromStr for Topology {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "torus" => Ok(Topology::Torus),
+            "cylinder" => Ok(Topology::Cylinder),
+            "bounded" => Ok(Topology::Bounded),
+            _ => Err(format!("unknown topology: {s}")),
+        }
+    }
+}
+
+impl fmt::Display for Topology {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Topology::Torus => write!(f, "torus"),
+            Topology::Cylinder => write!(f, "cylinder"),
+            Topology::Bounded => write!(f, "bounded"),
+        }
+    }
+}
+
+/// Cell storage strategy for a grid.
+///
+/// Lets callers query a grid uniformly regardless of how its cells are stored (e.g. a dense
+/// array or a sparse set of live cells).
+pub trait GridBackend {
+    /// Returns the number of live cells without iterating over them.
+    fn live_count(&self) -> usize;
+}
+
+/// A 2D grid for Conway's Game of Life, toroidal unless set otherwise
 ///
 /// Uses a flat vector internally for better cache locality
 #[derive(Clone, Debug)]
@@ -22,58 +125,429 @@ pub struct Grid {
     /// Flat storage of all cells.
     cells: Vec<CellState>,
     /// Width of th egrid in cells.
-    pub width: usize,
+    width: usize,
     /// Height of the grid in cells.
-    pub height: usize,
+    height: usize,
     /// Current number of alive cells.
     pub population: usize,
+    /// Generations since each cell was last alive (`0` while alive, `u16::MAX` if never alive).
+    since_death: Vec<u16>,
+    /// Generations each live cell has survived since it was born (`0` while dead).
+    ages: Vec<u32>,
+    /// Indices of cells that changed state since the changes were last taken, possibly with
+    /// repeats, or `None` if any cell may have changed.
+    changes: Option<Vec<usize>>,
+    /// XOR of [`cell_hash`] over all live cells, kept up to date as cells change.
+    state_hash: u64,
+    /// Whether the left and right edges are joined.
+    wrap_x: bool,
+    /// Whether the top and bottom edges are joined.
+    wrap_y: bool,
+    /// Whether each cell is a wall: permanently dead as far as stepping is concerned.
+    walls: Vec<bool>,
+    /// Whether walls count as live neighbors of the cells around them.
+    walls_alive: bool,
+    /// Generation each cell was last born in (`u64::MAX` if unknown), or `None` unless enabled
+    /// with [`Grid::set_birth_log`].
+    birth_log: Option<Vec<u64>>,
+    /// Generation recorded in the birth log for cells born from now on.
+    log_generation: u64,
 }
 
 impl Grid {
     /// Creates a new grid of given dimensions with all cells initially dead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension is zero or the grid would have more than
+    /// [`DEFAULT_MAX_CELLS`] cells; see [`Grid::try_new`] for a fallible version.
     pub fn new(width: usize, height: usize) -> Self {
-        assert!(
-            width > 0 && height > 0,
-            "Grid dimensions must be positive and non-zero"
-        );
+        match Self::try_new(width, height) {
+            Ok(grid) => grid,
+            Err(e) => panic!("Invalid grid dimensions: {e}"),
+        }
+    }
+
+    /// Creates a new grid of given dimensions with all cells initially dead.
+    ///
+    /// Fails if either dimension is zero or the grid would have more than
+    /// [`DEFAULT_MAX_CELLS`] cells.
+    pub fn try_new(width: usize, height: usize) -> Result<Self, GridError> {
+        Self::try_new_with_limit(width, height, DEFAULT_MAX_CELLS)
+    }
+
+    /// Creates a new grid of given dimensions with all cells initially dead, allowing at most
+    /// `max_cells` cells.
+    pub fn try_new_with_limit(
+        width: usize,
+        height: usize,
+        max_cells: usize,
+    ) -> Result<Self, GridError> {
+        let total_cells = Self::check_dimensions(width, height, max_cells)?;
 
-        Self {
-            cells: vec![CellState::Dead; width * height],
+        Ok(Self {
+            cells: vec![CellState::Dead; total_cells],
             width,
             height,
             population: 0,
+            since_death: vec![u16::MAX; total_cells],
+            ages: vec![0; total_cells],
+            changes: None,
+            state_hash: 0,
+            wrap_x: true,
+            wrap_y: true,
+            walls: vec![false; total_cells],
+            walls_alive: true,
+            birth_log: None,
+            log_generation: 0,
+        })
+    }
+
+    /// Creates a grid from rows of booleans, where `true` marks a live cell.
+    ///
+    /// Fails if there are no rows or columns, if rows differ in length, or if the grid would
+    /// have more than [`DEFAULT_MAX_CELLS`] cells.
+    pub fn from_bool_matrix(rows: &[Vec<bool>]) -> Result<Self, GridError> {
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(GridError::Empty);
+        }
+        if let Some((row, cells)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(GridError::RaggedRow {
+                row,
+                expected: width,
+                found: cells.len(),
+            });
         }
+
+        let mut grid = Self::try_new(width, rows.len())?;
+        for (row, cells) in rows.iter().enumerate() {
+            for (col, &alive) in cells.iter().enumerate() {
+                if alive {
+                    grid.set(row, col, CellState::Alive);
+                }
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Returns the grid as rows of booleans, where `true` marks a live cell.
+    pub fn to_bool_matrix(&self) -> Vec<Vec<bool>> {
+        self.cells
+            .chunks(self.width)
+            .map(|row| row.iter().map(CellState::is_alive).collect())
+            .collect()
+    }
+
+    /// Returns the width of the grid in cells.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the grid in cells.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the grid's (width, height) in cells.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
     }
 
     /// Gets the state of a cell at the given coordinates.
     ///
     /// Returns `None` if coordinates are out of bounds.
     pub fn get(&self, row: usize, col: usize) -> Option<CellState> {
-        // Ensure coordinate is in bounds
+        self.index(row, col).map(|idx| self.cells[idx])
+    }
+
+    /// Gets the state of a cell, wrapping along the axes the grid wraps on.
+    ///
+    /// Cells beyond an edge that doesn't wrap are dead.
+    pub fn get_wrapped(&self, row: isize, col: isize) -> CellState {
+        self.resolve(row, col)
+            .map_or(CellState::Dead, |(row, col)| {
+                self.cells[row * self.width + col]
+            })
+    }
+
+    /// Sets the state of a cell, wrapping along the axes the grid wraps on.
+    ///
+    /// Cells beyond an edge that doesn't wrap are left alone.
+    pub fn set_wrapped(&mut self, row: isize, col: isize, state: CellState) {
+        if let Some((row, col)) = self.resolve(row, col) {
+            self.set(row, col, state);
+        }
+    }
+
+    /// Returns whether the left and right edges are joined.
+    pub fn wrap_x(&self) -> bool {
+        self.wrap_x
+    }
+
+    /// Returns whether the top and bottom edges are joined.
+    pub fn wrap_y(&self) -> bool {
+        self.wrap_y
+    }
+
+    /// Sets whether the grid wraps horizontally and vertically.
+    pub fn set_wrap(&mut self, wrap_x: bool, wrap_y: bool) {
+        if (wrap_x, wrap_y) != (self.wrap_x, self.wrap_y) {
+            self.wrap_x = wrap_x;
+            self.wrap_y = wrap_y;
+            // Cells along the edges see different neighbors now
+            self.mark_all_changed();
+        }
+    }
+
+    /// Sets how the grid's edges connect from a named topology.
+    pub fn set_topology(&mut self, topology: Topology) {
+        let (wrap_x, wrap_y) = topology.wraps();
+        self.set_wrap(wrap_x, wrap_y);
+    }
+
+    /// Maps possibly out-of-range coordinates onto the grid, wrapping along the axes the grid
+    /// wraps on.
+    ///
+    /// Returns `None` for coordinates beyond an edge that doesn't wrap.
+    pub fn resolve(&self, row: isize, col: isize) -> Option<(usize, usize)> {
+        Some((
+            wrap_axis(row, self.height, self.wrap_y)?,
+            wrap_axis(col, self.width, self.wrap_x)?,
+        ))
+    }
+
+    /// Returns the offset from cell `a` to cell `b` as (rows, cols), positive downwards and
+    /// rightwards, taking the shorter way around along the axes the grid wraps on.
+    ///
+    /// Along a wrapping axis the offset is at most half its length either way, so cells on
+    /// opposite edges are 1 apart rather than a whole width.
+    pub fn wrapped_delta(&self, a: (usize, usize), b: (usize, usize)) -> (isize, isize) {
+        (
+            axis_delta(a.0, b.0, self.height, self.wrap_y),
+            axis_delta(a.1, b.1, self.width, self.wrap_x),
+        )
+    }
+
+    /// Returns the distance between two cells in king moves, the larger of the two offsets from
+    /// [`Grid::wrapped_delta`].
+    ///
+    /// Neighboring cells are 1 apart, including across an edge that wraps.
+    pub fn wrapped_distance(&self, a: (usize, usize), b: (usize, usize)) -> usize {
+        let (rows, cols) = self.wrapped_delta(a, b);
+        rows.unsigned_abs().max(cols.unsigned_abs())
+    }
+
+    /// Sets every cell covered by a brush of the given shape and radius centered on a cell,
+    /// wrapping at its edges like neighbors do.
+    ///
+    /// A radius of `0` covers only the center cell.
+    pub fn paint(
+        &mut self,
+        row: usize,
+        col: usize,
+        radius: usize,
+        shape: BrushShape,
+        state: CellState,
+    ) {
+        for (row, col) in self.brush_cells(row, col, radius, shape) {
+            self.set(row, col, state);
+        }
+    }
+
+    /// Adds or removes walls on every cell covered by a brush, like [`Grid::paint`].
+    pub fn paint_walls(
+        &mut self,
+        row: usize,
+        col: usize,
+        radius: usize,
+        shape: BrushShape,
+        wall: bool,
+    ) {
+        for (row, col) in self.brush_cells(row, col, radius, shape) {
+            self.set_wall(row, col, wall);
+        }
+    }
+
+    /// Returns the cells covered by a brush of the given shape and radius centered on a cell.
+    fn brush_cells(
+        &self,
+        row: usize,
+        col: usize,
+        radius: usize,
+        shape: BrushShape,
+    ) -> Vec<(usize, usize)> {
+        let r = radius as isize;
+        (-r..=r)
+            .flat_map(|dr| (-r..=r).map(move |dc| (dr, dc)))
+            .filter(|&(dr, dc)| shape == BrushShape::Square || dr * dr + dc * dc <= r * r)
+            .filter_map(|(dr, dc)| self.resolve(row as isize + dr, col as isize + dc))
+            .collect()
+    }
+
+    /// Returns true if the cell at the given coordinates is a wall.
+    ///
+    /// Coordinates out of bounds are never walls.
+    pub fn is_wall(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width && self.walls[row * self.width + col]
+    }
+
+    /// Makes a cell a wall or an ordinary cell again, killing it if it becomes a wall.
+    ///
+    /// Walls never change when the grid is stepped. Returns whether the cell was a wall, or
+    /// `None` if coordinates are out of bounds.
+    pub fn set_wall(&mut self, row: usize, col: usize, wall: bool) -> Option<bool> {
         if row >= self.height || col >= self.width {
             return None;
         }
 
-        Some(self.cells[row * self.width + col])
+        let idx = row * self.width + col;
+        let old = self.walls[idx];
+        if old != wall {
+            if wall {
+                self.set(row, col, CellState::Dead);
+            }
+            self.walls[idx] = wall;
+            // Neighbors see a different count if walls are alive
+            self.record_change(idx);
+        }
+        Some(old)
+    }
+
+    /// Removes every wall.
+    pub fn clear_walls(&mut self) {
+        self.walls.fill(false);
+        self.changes = None;
     }
 
-    /// Gets the state of a cell with toroidal wrapping.
-    pub fn get_wrapped(&self, row: isize, col: isize) -> CellState {
-        let row = row.rem_euclid(self.height as isize) as usize;
-        let col = col.rem_euclid(self.width as isize) as usize;
-        self.cells[row * self.width + col]
+    /// Returns whether walls count as live neighbors of the cells around them.
+    pub fn walls_alive(&self) -> bool {
+        self.walls_alive
+    }
+
+    /// Sets whether walls count as live neighbors of the cells around them.
+    pub fn set_walls_alive(&mut self, walls_alive: bool) {
+        if walls_alive != self.walls_alive {
+            self.walls_alive = walls_alive;
+            self.mark_all_changed();
+        }
+    }
+
+    /// Returns the index of a cell in the per-cell buffers, or `None` if the coordinates are out
+    /// of bounds.
+    fn index(&self, row: usize, col: usize) -> Option<usize> {
+        (row < self.height && col < self.width).then(|| row * self.width + col)
+    }
+
+    /// Gets the number of generations since a cell was last alive.
+    ///
+    /// Returns `0` for live cells and `u16::MAX` for cells that have never been alive, including
+    /// any out of bounds.
+    pub fn since_death(&self, row: usize, col: usize) -> u16 {
+        self.index(row, col)
+            .map_or(u16::MAX, |idx| self.since_death[idx])
+    }
+
+    /// Sets the number of generations since a cell was last alive.
+    ///
+    /// Does nothing if the coordinates are out of bounds.
+    pub fn set_since_death(&mut self, row: usize, col: usize, generations: u16) {
+        if let Some(idx) = self.index(row, col) {
+            self.since_death[idx] = generations;
+        }
+    }
+
+    /// Gets the number of generations a cell has survived since it was born.
+    ///
+    /// Returns `0` for dead cells, cells born in the latest generation, and out of bounds
+    /// coordinates.
+    pub fn age(&self, row: usize, col: usize) -> u32 {
+        self.index(row, col).map_or(0, |idx| self.ages[idx])
+    }
+
+    /// Turns the per-cell birth log on or off.
+    ///
+    /// The log costs eight bytes per cell, so it's off by default. Cells alive when it's turned
+    /// on have no known birth generation.
+    pub fn set_birth_log(&mut self, enabled: bool) {
+        if enabled != self.birth_log.is_some() {
+            self.birth_log = enabled.then(|| vec![u64::MAX; self.cells.len()]);
+        }
+    }
+
+    /// Returns true if the birth log is on.
+    pub fn birth_log_enabled(&self) -> bool {
+        self.birth_log.is_some()
+    }
+
+    /// Sets the generation recorded in the birth log for cells born from now on.
+    pub fn set_log_generation(&mut self, generation: u64) {
+        self.log_generation = generation;
+    }
+
+    /// Gets the generation a live cell was born in.
+    ///
+    /// Returns `None` for dead cells, if the birth log is off, if the cell was already alive
+    /// when the log was turned on, or if the coordinates are out of bounds.
+    pub fn born_at(&self, row: usize, col: usize) -> Option<u64> {
+        let idx = self.index(row, col)?;
+        let log = self.birth_log.as_ref()?;
+        (self.cells[idx].is_alive() && log[idx] != u64::MAX).then_some(log[idx])
+    }
+
+    /// Returns true if a cell was born or died in the latest generation, or was set by hand
+    /// since.
+    ///
+    /// Returns false if the coordinates are out of bounds.
+    pub fn changed_last_step(&self, row: usize, col: usize) -> bool {
+        let Some(idx) = self.index(row, col) else {
+            return false;
+        };
+        match self.cells[idx] {
+            CellState::Alive => self.ages[idx] == 0,
+            CellState::Dead => self.since_death[idx] == 1,
+        }
+    }
+
+    /// Returns the age of the oldest live cell, or `None` if the grid is empty.
+    pub fn max_age(&self) -> Option<u32> {
+        (!self.is_empty()).then(|| self.ages.iter().copied().max().unwrap_or(0))
+    }
+
+    /// Counts one more generation on the age of every live cell and the trail of every dead
+    /// cell.
+    pub fn age_cells(&mut self) {
+        for ((since_death, age), state) in self
+            .since_death
+            .iter_mut()
+            .zip(&mut self.ages)
+            .zip(&self.cells)
+        {
+            if state.is_alive() {
+                *age = age.saturating_add(1);
+            } else {
+                *since_death = since_death.saturating_add(1);
+            }
+        }
+    }
+
+    /// Forgets all trails, leaving only live cells marked.
+    pub fn clear_trails(&mut self) {
+        for (since_death, state) in self.since_death.iter_mut().zip(&self.cells) {
+            *since_death = if state.is_alive() { 0 } else { u16::MAX };
+        }
     }
 
     /// Sets the state of a cell at the given coordinates.
     ///
-    /// Returns `None` if coordinates are out of bounds.
-    /// Automatically updates the population count.
+    /// Returns the previous state, or `None` if coordinates are out of bounds or the cell is a
+    /// wall being brought to life, which stays dead.
+    /// Automatically updates the population count and trail counter.
     pub fn set(&mut self, row: usize, col: usize, state: CellState) -> Option<CellState> {
-        if row >= self.height || col >= self.width {
+        let idx = self.index(row, col)?;
+        if self.walls[idx] && state.is_alive() {
             return None;
         }
-
-        let idx = row * self.width + col;
         let old = self.cells[idx];
 
         // only update on state changes
@@ -85,12 +559,99 @@ impl Grid {
                 self.population += 1;
             }
             self.cells[idx] = state;
+            self.since_death[idx] = if state.is_alive() { 0 } else { 1 };
+            self.ages[idx] = 0;
+            if state.is_alive()
+                && let Some(log) = &mut self.birth_log
+            {
+                log[idx] = self.log_generation;
+            }
+            self.state_hash ^= cell_hash(idx);
+            self.record_change(idx);
         }
 
         Some(old)
     }
 
+    /// Records that the cell at `idx` changed state.
+    ///
+    /// Once a sizable fraction of the grid has changed, tracking individual cells costs more
+    /// than it saves, so everything is treated as changed instead.
+    fn record_change(&mut self, idx: usize) {
+        if let Some(changes) = &mut self.changes {
+            changes.push(idx);
+            if changes.len() > self.cells.len() / 4 {
+                self.changes = None;
+            }
+        }
+    }
+
+    /// Marks every cell as possibly changed.
+    pub fn mark_all_changed(&mut self) {
+        self.changes = None;
+    }
+
+    /// Returns the (row, col) of every cell that changed state since the last call, possibly
+    /// with repeats, or `None` if any cell may have changed.
+    ///
+    /// Changes are tracked again from scratch after each call.
+    pub fn take_changes(&mut self) -> Option<Vec<(usize, usize)>> {
+        let changes = self.changes.replace(Vec::new())?;
+        Some(
+            changes
+                .into_iter()
+                .map(|idx| (idx / self.width, idx % self.width))
+                .collect(),
+        )
+    }
+
+    /// Sets the given cells alive, offset by (`row`, `col`).
+    ///
+    /// Cells that fall outside the grid or on a wall are skipped. Returns the number of cells
+    /// placed.
+    pub fn stamp(&mut self, cells: &[(usize, usize)], row: usize, col: usize) -> usize {
+        cells
+            .iter()
+            .filter(|&&(r, c)| self.set(row + r, col + c, CellState::Alive).is_some())
+            .count()
+    }
+
+    /// Moves every live cell by (`d_row`, `d_col`), keeping its age.
+    ///
+    /// Cells moved past an edge come back on the opposite side if `wrap` and are dropped
+    /// otherwise, so the population only shrinks when clipping. Walls stay where they are, and
+    /// cells moved onto one are dropped too. Trails are cleared.
+    pub fn translate(&mut self, d_row: isize, d_col: isize, wrap: bool) {
+        let moved: Vec<_> = self
+            .iter_alive_cells()
+            .filter_map(|(row, col)| {
+                let idx = row * self.width + col;
+                let born = self.birth_log.as_ref().map_or(u64::MAX, |log| log[idx]);
+                Some((
+                    wrap_axis(row as isize + d_row, self.height, wrap)?,
+                    wrap_axis(col as isize + d_col, self.width, wrap)?,
+                    self.ages[idx],
+                    born,
+                ))
+            })
+            .collect();
+
+        self.clear();
+        for (row, col, age, born) in moved {
+            self.set(row, col, CellState::Alive);
+            let idx = row * self.width + col;
+            if self.cells[idx].is_alive() {
+                self.ages[idx] = age;
+                if let Some(log) = &mut self.birth_log {
+                    log[idx] = born;
+                }
+            }
+        }
+    }
+
     /// Counts the number of alive neighbors in Moore neighborhood of a cell.
+    ///
+    /// Beyond the edges, neighbors wrap around or are dead depending on the axis.
     pub fn count_neighbors(&self, row: usize, col: usize) -> u8 {
         const NEIGHBORS: [(isize, isize); 8] = [
             (-1, -1),
@@ -103,20 +664,153 @@ impl Grid {
             (1, 1),
         ];
 
+        // Interior cells, by far the most common, need neither wrapping nor bounds checks
+        if row > 0 && col > 0 && row + 1 < self.height && col + 1 < self.width {
+            let above = (row - 1) * self.width + col;
+            let here = row * self.width + col;
+            let below = (row + 1) * self.width + col;
+            return [
+                above - 1,
+                above,
+                above + 1,
+                here - 1,
+                here + 1,
+                below - 1,
+                below,
+                below + 1,
+            ]
+            .into_iter()
+            .filter(|&idx| self.counts_as_alive(idx))
+            .count() as u8;
+        }
+
         NEIGHBORS
             .iter()
-            .filter(|&&(dr, dc)| {
-                self.get_wrapped(row as isize + dr, col as isize + dc)
-                    .is_alive()
-            })
+            .filter_map(|&(dr, dc)| self.resolve(row as isize + dr, col as isize + dc))
+            .filter(|&(row, col)| self.counts_as_alive(row * self.width + col))
             .count() as u8
     }
 
+    /// Counts the number of alive cells in the Moore neighborhood of a cell including the cell
+    /// itself, for rules that count the center.
+    ///
+    /// A cell whose coordinates are out of bounds counts as dead.
+    pub fn count_self_and_neighbors(&self, row: usize, col: usize) -> u8 {
+        let here = u8::from(
+            self.index(row, col)
+                .is_some_and(|idx| self.counts_as_alive(idx)),
+        );
+        self.count_neighbors(row, col) + here
+    }
+
+    /// Returns true if the cell at `idx` counts as a live neighbor.
+    fn counts_as_alive(&self, idx: usize) -> bool {
+        self.cells[idx].is_alive() || (self.walls_alive && self.walls[idx])
+    }
+
+    /// Counts how many live cells have each number of live neighbors.
+    ///
+    /// Index `n` of the result holds the number of live cells with exactly `n` live neighbors.
+    pub fn neighbor_histogram(&self) -> [usize; 9] {
+        let mut histogram = [0; 9];
+        for (row, col) in self.iter_alive_cells() {
+            histogram[self.count_neighbors(row, col) as usize] += 1;
+        }
+        histogram
+    }
+
+    /// Counts groups of live cells connected to each other through live neighbors.
+    ///
+    /// Cells touching side to side are always connected; those touching only at a corner are
+    /// connected if `diagonal`. Connections wrap around the edges like neighbors do.
+    pub fn count_clusters(&self, diagonal: bool) -> usize {
+        const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+        const ALL: [(isize, isize); 8] = [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ];
+        let directions: &[(isize, isize)] = if diagonal { &ALL } else { &ORTHOGONAL };
+
+        let mut visited = vec![false; self.cells.len()];
+        let mut stack = Vec::new();
+        let mut clusters = 0;
+        for (row, col) in self.iter_alive_cells() {
+            if std::mem::replace(&mut visited[row * self.width + col], true) {
+                continue;
+            }
+            // Flood the new cluster so none of its other cells start one of their own
+            clusters += 1;
+            stack.push((row, col));
+            while let Some((row, col)) = stack.pop() {
+                for &(dr, dc) in directions {
+                    let Some((row, col)) = self.resolve(row as isize + dr, col as isize + dc)
+                    else {
+                        continue;
+                    };
+                    let idx = row * self.width + col;
+                    if self.cells[idx].is_alive() && !std::mem::replace(&mut visited[idx], true) {
+                        stack.push((row, col));
+                    }
+                }
+            }
+        }
+        clusters
+    }
+
+    /// Returns the number of cells in a grid of the given dimensions.
+    ///
+    /// Fails if either dimension is zero or the grid would have more than `max_cells` cells,
+    /// including when the count overflows.
+    pub fn check_dimensions(
+        width: usize,
+        height: usize,
+        max_cells: usize,
+    ) -> Result<usize, GridError> {
+        if width == 0 || height == 0 {
+            return Err(GridError::Empty);
+        }
+        width
+            .checked_mul(height)
+            .filter(|&total_cells| total_cells <= max_cells)
+            .ok_or(GridError::TooLarge {
+                width,
+                height,
+                max_cells,
+            })
+    }
+
     /// Resizes the grid, preserving existing cells that fit within the new dimensions.
     ///
     /// Cells outside the new dimensions are discarded. New areas are initalized dead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension is zero or the grid would have more than
+    /// [`DEFAULT_MAX_CELLS`] cells; see [`Grid::try_resize`] for a fallible version.
     pub fn resize(&mut self, new_width: usize, new_height: usize) {
+        if let Err(e) = self.try_resize(new_width, new_height) {
+            panic!("Invalid grid dimensions: {e}");
+        }
+    }
+
+    /// Resizes the grid like [`Grid::resize`], leaving it untouched if either dimension is zero
+    /// or the grid would have more than [`DEFAULT_MAX_CELLS`] cells.
+    pub fn try_resize(&mut self, new_width: usize, new_height: usize) -> Result<(), GridError> {
+        Self::check_dimensions(new_width, new_height, DEFAULT_MAX_CELLS)?;
         let mut new_cells = vec![CellState::Dead; new_width * new_height];
+        let mut new_since_death = vec![u16::MAX; new_width * new_height];
+        let mut new_ages = vec![0; new_width * new_height];
+        let mut new_walls = vec![false; new_width * new_height];
+        let mut new_birth_log = self
+            .birth_log
+            .as_ref()
+            .map(|_| vec![u64::MAX; new_width * new_height]);
 
         let mut new_pop = 0;
 
@@ -130,6 +824,12 @@ impl Grid {
                 let new_idx = row * new_width + col;
                 let state = self.cells[old_idx];
                 new_cells[new_idx] = state;
+                new_since_death[new_idx] = self.since_death[old_idx];
+                new_ages[new_idx] = self.ages[old_idx];
+                new_walls[new_idx] = self.walls[old_idx];
+                if let (Some(new_log), Some(log)) = (&mut new_birth_log, &self.birth_log) {
+                    new_log[new_idx] = log[old_idx];
+                }
                 if state.is_alive() {
                     new_pop += 1
                 }
@@ -140,13 +840,80 @@ impl Grid {
         self.width = new_width;
         self.height = new_height;
         self.population = new_pop;
-        // Trail length is preserved during resize
+        // Trails and ages are preserved during resize
+        self.since_death = new_since_death;
+        self.ages = new_ages;
+        self.walls = new_walls;
+        self.birth_log = new_birth_log;
+        self.changes = None;
+        self.state_hash = self.iter_alive_cells().fold(0, |hash, (row, col)| {
+            hash ^ cell_hash(row * new_width + col)
+        });
+        Ok(())
     }
 
     /// Clears all cells, setting them to dead.
+    ///
+    /// Walls are left in place; see [`Grid::clear_walls`].
     pub fn clear(&mut self) {
         self.cells.fill(CellState::Dead);
+        self.since_death.fill(u16::MAX);
+        self.ages.fill(0);
+        if let Some(log) = &mut self.birth_log {
+            log.fill(u64::MAX);
+        }
         self.population = 0;
+        self.changes = None;
+        self.state_hash = 0;
+    }
+
+    /// Returns a hash of which cells are alive, maintained incrementally as cells change.
+    ///
+    /// Equal grids of the same size always have equal hashes; different ones almost never do.
+    pub fn state_hash(&self) -> u64 {
+        self.state_hash
+    }
+
+    /// Returns a short code identifying the arrangement of live cells, for checking that two
+    /// boards hold the same configuration.
+    ///
+    /// Only the contents of the live cells' bounding box are hashed, so the code is the same
+    /// wherever the cells sit and whatever the grid's size, but patterns wrapping around an edge
+    /// aren't recognized as one piece. The code is stable across runs and builds.
+    pub fn fingerprint(&self) -> String {
+        /// Lowercase RFC 4648 base32 alphabet.
+        const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+        /// Base32 digits in the code (50 bits).
+        const DIGITS: u32 = 10;
+
+        let (hash, _) = self.shape_hash();
+        (0..DIGITS)
+            .map(|digit| char::from(ALPHABET[(hash >> (digit * 5)) as usize & 31]))
+            .collect()
+    }
+
+    /// Returns a hash of the arrangement of live cells along with the (top, left) corner of
+    /// their bounding box, which is `(0, 0)` for an empty grid.
+    ///
+    /// Like [`Grid::fingerprint`], the hash only depends on the contents of the bounding box, so
+    /// a pattern has the same hash wherever it sits and comparing corners tells how far it moved.
+    pub fn shape_hash(&self) -> (u64, (usize, usize)) {
+        /// FNV-1a 64-bit parameters.
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let (top, left, height, width) = self.live_bounds().unwrap_or((0, 0, 0, 0));
+        let offsets = self
+            .iter_alive_cells()
+            .map(|(row, col)| (row - top) * width + (col - left));
+        let hash = [height, width]
+            .into_iter()
+            .chain(offsets)
+            .flat_map(|value| (value as u64).to_le_bytes())
+            .fold(FNV_OFFSET, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            });
+        (hash, (top, left))
     }
 
     /// Checks if the grid is empty (no alive cells).
@@ -154,6 +921,20 @@ impl Grid {
         self.population == 0
     }
 
+    /// Returns the smallest rectangle containing every live cell as (top, left, height, width),
+    /// or `None` if the grid is empty.
+    pub fn live_bounds(&self) -> Option<(usize, usize, usize, usize)> {
+        let mut alive = self.iter_alive_cells();
+        let (row, col) = alive.next()?;
+        let (top, left, bottom, right) = alive.fold(
+            (row, col, row, col),
+            |(top, left, bottom, right), (row, col)| {
+                (top.min(row), left.min(col), bottom.max(row), right.max(col))
+            },
+        );
+        Some((top, left, bottom - top + 1, right - left + 1))
+    }
+
     /// Returns an iterator over all cells with their (row, col) coordinates.
     pub fn iter_cells(&self) -> impl Iterator<Item = ((usize, usize), CellState)> + '_ {
         self.cells.iter().enumerate().map(move |(idx, &state)| {
@@ -163,6 +944,28 @@ impl Grid {
         })
     }
 
+    /// Returns an iterator over the cells in a rectangular region with their (row, col)
+    /// coordinates, in row-major order.
+    ///
+    /// The region is clipped to the grid's bounds rather than wrapped.
+    pub fn iter_region(
+        &self,
+        top: usize,
+        left: usize,
+        height: usize,
+        width: usize,
+    ) -> impl Iterator<Item = ((usize, usize), CellState)> + '_ {
+        let bottom = top.saturating_add(height).min(self.height);
+        let right = left.saturating_add(width).min(self.width);
+        (top..bottom).flat_map(move |row| {
+            let start = row * self.width;
+            self.cells[start + left.min(right)..start + right]
+                .iter()
+                .enumerate()
+                .map(move |(offset, &state)| ((row, left + offset), state))
+        })
+    }
+
     /// Returns an iterator over only live cells.
     pub fn iter_alive_cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
         self.cells
@@ -176,28 +979,310 @@ impl Grid {
             })
     }
 
-    /// Renders the grid to a string using Unicode block characters.
+    /// Renders the grid to a string using Unicode block characters, two characters per cell.
     pub fn render(&self) -> String {
-        // Pre-allocates the string with the exact capacity needed.
-        // Each cell is 2 chars wide, plus newlines
-        let mut result = String::with_capacity(self.height * (self.width * 2 + 1));
+        let mut result = String::new();
+        self.render_into(&mut result);
+        result
+    }
+
+    /// Renders the grid like [`Grid::render`] into a reused buffer, replacing its contents.
+    pub fn render_into(&self, buf: &mut String) {
+        self.render_block_into(2, buf);
+    }
+
+    /// Renders the grid to a string using Unicode block characters, `cell_width` characters
+    /// per cell.
+    pub fn render_block(&self, cell_width: usize) -> String {
+        let mut result = String::new();
+        self.render_block_into(cell_width, &mut result);
+        result
+    }
+
+    /// Renders the grid like [`Grid::render_block`] into a reused buffer, replacing its
+    /// contents.
+    pub fn render_block_into(&self, cell_width: usize, buf: &mut String) {
+        buf.clear();
+        // Each block character is 3 bytes, plus newlines
+        buf.reserve(self.height * (self.width * cell_width * 3 + 1));
 
         for row in 0..self.height {
             for col in 0..self.width {
                 let idx = row * self.width + col;
-                let cell_str = match self.cells[idx] {
-                    CellState::Alive => "██",
-                    CellState::Dead => "  ",
+                let cell_char = match self.cells[idx] {
+                    CellState::Alive => '█',
+                    CellState::Dead => ' ',
                 };
-                result.push_str(cell_str);
+                for _ in 0..cell_width {
+                    buf.push(cell_char);
+                }
             }
             if row < self.height - 1 {
-                result.push('\n');
+                buf.push('\n');
             }
         }
+    }
 
+    /// Renders the grid to a string using half-block characters, packing 2 vertically stacked
+    /// cells per character.
+    ///
+    /// Cells beyond the bottom edge in the last row of characters are treated as dead.
+    pub fn render_halfblock(&self) -> String {
+        let mut result = String::new();
+        self.render_halfblock_into(&mut result);
         result
     }
+
+    /// Renders the grid like [`Grid::render_halfblock`] into a reused buffer, replacing its
+    /// contents.
+    pub fn render_halfblock_into(&self, buf: &mut String) {
+        let char_rows = self.height.div_ceil(2);
+        buf.clear();
+        buf.reserve(char_rows * (self.width * 3 + 1));
+
+        for char_row in 0..char_rows {
+            for col in 0..self.width {
+                buf.push(self.halfblock_char(char_row, col));
+            }
+            if char_row < char_rows - 1 {
+                buf.push('\n');
+            }
+        }
+    }
+
+    /// Renders the grid to a string using Braille patterns, packing 2×4 cells per character.
+    ///
+    /// Cells beyond the grid's edges in the last row or column of characters are treated as dead.
+    pub fn render_braille(&self) -> String {
+        let mut result = String::new();
+        self.render_braille_into(&mut result);
+        result
+    }
+
+    /// Renders the grid like [`Grid::render_braille`] into a reused buffer, replacing its
+    /// contents.
+    pub fn render_braille_into(&self, buf: &mut String) {
+        let char_rows = self.height.div_ceil(4);
+        let char_cols = self.width.div_ceil(2);
+        buf.clear();
+        buf.reserve(char_rows * (char_cols * 3 + 1));
+
+        for char_row in 0..char_rows {
+            for char_col in 0..char_cols {
+                buf.push(self.braille_char(char_row, char_col));
+            }
+            if char_row < char_rows - 1 {
+                buf.push('\n');
+            }
+        }
+    }
+
+    /// Renders the grid to a string of shade characters, each summarizing a `block_w`×`block_h`
+    /// block of cells by the fraction of them that are alive.
+    ///
+    /// Cells beyond the grid's edges in the last row or column of characters are treated as dead.
+    pub fn render_shaded(&self, block_w: usize, block_h: usize) -> String {
+        let mut result = String::new();
+        self.render_shaded_into(block_w, block_h, &mut result);
+        result
+    }
+
+    /// Renders the grid like [`Grid::render_shaded`] into a reused buffer, replacing its
+    /// contents.
+    pub fn render_shaded_into(&self, block_w: usize, block_h: usize, buf: &mut String) {
+        let char_rows = self.height.div_ceil(block_h);
+        let char_cols = self.width.div_ceil(block_w);
+        buf.clear();
+        buf.reserve(char_rows * (char_cols * 3 + 1));
+
+        for char_row in 0..char_rows {
+            for char_col in 0..char_cols {
+                buf.push(self.shaded_char(char_row, char_col, block_w, block_h));
+            }
+            if char_row < char_rows - 1 {
+                buf.push('\n');
+            }
+        }
+    }
+
+    /// Returns the shade character for the `block_w`×`block_h` block of cells at a character
+    /// position.
+    ///
+    /// Empty blocks are blank and only full blocks are solid, so a single live cell never
+    /// disappears and a solid block is never overstated. Cells beyond the grid's edges are
+    /// treated as dead.
+    pub fn shaded_char(
+        &self,
+        char_row: usize,
+        char_col: usize,
+        block_w: usize,
+        block_h: usize,
+    ) -> char {
+        assert!(
+            block_w > 0 && block_h > 0,
+            "Block dimensions must be positive and non-zero"
+        );
+
+        let live = self
+            .iter_region(char_row * block_h, char_col * block_w, block_h, block_w)
+            .filter(|(_, state)| state.is_alive())
+            .count();
+        let total = block_w * block_h;
+
+        match live {
+            0 => SHADES[0],
+            _ if live == total => SHADES[SHADES.len() - 1],
+            // Partial blocks map evenly onto the intermediate shades
+            _ => SHADES[1 + live * (SHADES.len() - 2) / total],
+        }
+    }
+
+    /// Returns the half-block character showing the 2 vertically stacked cells at a character
+    /// position.
+    ///
+    /// Cells beyond the bottom edge are treated as dead.
+    pub fn halfblock_char(&self, char_row: usize, col: usize) -> char {
+        let top = self.get(char_row * 2, col).is_some_and(|s| s.is_alive());
+        let bottom = self
+            .get(char_row * 2 + 1, col)
+            .is_some_and(|s| s.is_alive());
+        match (top, bottom) {
+            (true, true) => '█',
+            (true, false) => '▀',
+            (false, true) => '▄',
+            (false, false) => ' ',
+        }
+    }
+
+    /// Returns the Braille pattern showing the 2×4 cells at a character position.
+    ///
+    /// Cells beyond the grid's edges are treated as dead.
+    pub fn braille_char(&self, char_row: usize, char_col: usize) -> char {
+        /// Dot bit for each (row, col) offset within a 2×4 Braille character.
+        const DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+        let mut bits = 0;
+        for (dr, row_dots) in DOTS.iter().enumerate() {
+            for (dc, dot) in row_dots.iter().enumerate() {
+                let row = char_row * 4 + dr;
+                let col = char_col * 2 + dc;
+                if self.get(row, col).is_some_and(|state| state.is_alive()) {
+                    bits |= dot;
+                }
+            }
+        }
+        // All codepoints in the Braille Patterns block are valid chars
+        char::from_u32(BRAILLE_BASE + bits).unwrap_or(' ')
+    }
+}
+
+/// Scrambles a cell index into a well-distributed hash (SplitMix64 finalizer).
+fn cell_hash(idx: usize) -> u64 {
+    let mut x = (idx as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Maps a possibly out-of-range coordinate onto an axis of length `len`, wrapping around if
+/// `wrap` or returning `None` if it falls beyond the edge otherwise.
+fn wrap_axis(pos: isize, len: usize, wrap: bool) -> Option<usize> {
+    if wrap {
+        Some(pos.rem_euclid(len as isize) as usize)
+    } else {
+        usize::try_from(pos).ok().filter(|&pos| pos < len)
+    }
+}
+
+/// Returns the signed offset from `from` to `to` on an axis of length `len`, going around the
+/// edge instead if `wrap` and that's shorter.
+fn axis_delta(from: usize, to: usize, len: usize, wrap: bool) -> isize {
+    let delta = to as isize - from as isize;
+    if !wrap {
+        return delta;
+    }
+    let len = len as isize;
+    let forward = delta.rem_euclid(len);
+    if forward > len / 2 {
+        forward - len
+    } else {
+        forward
+    }
+}
+
+/// Returns the default for the wrapping flags of a deserialized grid.
+#[cfg(feature = "serde")]
+fn default_wrap() -> bool {
+    true
+}
+
+/// Error returned when building a [`Grid`] from external data fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// The data has no rows or no columns.
+    Empty,
+    /// A row's length differs from the first row's.
+    RaggedRow {
+        /// Index of the offending row.
+        row: usize,
+        /// Length of the first row.
+        expected: usize,
+        /// Length of the offending row.
+        found: usize,
+    },
+    /// The grid would have more cells than allowed.
+    TooLarge {
+        /// Requested width in cells.
+        width: usize,
+        /// Requested height in cells.
+        height: usize,
+        /// Most cells allowed.
+        max_cells: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid must have at least one row and column"),
+            GridError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(f, "row {row} has {found} cells, expected {expected}"),
+            GridError::TooLarge {
+                width,
+                height,
+                max_cells,
+            } => write!(
+                f,
+                "{width}x{height} grid exceeds the {max_cells} cell limit"
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
+
+/// Grids are equal if they have the same dimensions, live cells, and walls.
+///
+/// Trails and change tracking are bookkeeping rather than state, so grids reached by different
+/// histories (e.g. stepped versus loaded directly) still compare equal.
+impl PartialEq for Grid {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.cells == other.cells
+            && self.walls == other.walls
+    }
+}
+
+impl Eq for Grid {}
+
+impl GridBackend for Grid {
+    fn live_count(&self) -> usize {
+        self.population
+    }
 }
 
 impl fmt::Display for Grid {
@@ -205,3 +1290,578 @@ impl fmt::Display for Grid {
         write!(f, "{}", self.render())
     }
 }
+
+/// Serializes the dimensions, wrapping, cells, and walls. Cells are stored as the lengths of
+/// alternating runs of dead and live cells in row-major order, starting with dead, and walls as
+/// a list of row-major indices.
+#[cfg(feature = "serde")]
+impl Serialize for Grid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut runs = Vec::new();
+        let mut current = CellState::Dead;
+        let mut len = 0;
+        for &cell in &self.cells {
+            if cell != current {
+                runs.push(len);
+                current = cell;
+                len = 0;
+            }
+            len += 1;
+        }
+        runs.push(len);
+
+        let walls: Vec<usize> = (0..self.walls.len())
+            .filter(|&idx| self.walls[idx])
+            .collect();
+
+        let mut state = serializer.serialize_struct("Grid", 6)?;
+        state.serialize_field("width", &self.width)?;
+        state.serialize_field("height", &self.height)?;
+        state.serialize_field("wrap_x", &self.wrap_x)?;
+        state.serialize_field("wrap_y", &self.wrap_y)?;
+        state.serialize_field("runs", &runs)?;
+        state.serialize_field("walls", &walls)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Grid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Grid")]
+        struct Fields {
+            width: usize,
+            height: usize,
+            #[serde(default = "default_wrap")]
+            wrap_x: bool,
+            #[serde(default = "default_wrap")]
+            wrap_y: bool,
+            runs: Vec<usize>,
+            #[serde(default)]
+            walls: Vec<usize>,
+        }
+
+        let Fields {
+            width,
+            height,
+            wrap_x,
+            wrap_y,
+            runs,
+            walls,
+        } = Fields::deserialize(deserializer)?;
+        let mut grid = Grid::try_new(width, height).map_err(de::Error::custom)?;
+        let total = runs
+            .iter()
+            .try_fold(0usize, |total, &len| total.checked_add(len));
+        if total != width.checked_mul(height) {
+            return Err(de::Error::custom(format!(
+                "cell runs must cover {width}x{height} cells"
+            )));
+        }
+
+        grid.wrap_x = wrap_x;
+        grid.wrap_y = wrap_y;
+        let mut idx = 0;
+        for (i, &len) in runs.iter().enumerate() {
+            if i % 2 == 1 {
+                for cell in idx..idx + len {
+                    grid.set(cell / width, cell % width, CellState::Alive);
+                }
+            }
+            idx += len;
+        }
+        for idx in walls {
+            if grid.set_wall(idx / width, idx % width, true).is_none() {
+                return Err(de::Error::custom(format!("wall {idx} is outside the grid")));
+            }
+        }
+        grid.mark_all_changed();
+        Ok(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    /// Builds a grid of the given size with just the given cells alive.
+    fn grid_with(width: usize, height: usize, cells: &[(usize, usize)]) -> Grid {
+        let mut grid = Grid::new(width, height);
+        for &(row, col) in cells {
+            grid.set(row, col, CellState::Alive);
+        }
+        grid
+    }
+
+    /// Builds a grid of the given size with each cell alive with probability `density`.
+    fn random_grid(rng: &mut StdRng, width: usize, height: usize, density: f64) -> Grid {
+        let mut grid = Grid::new(width, height);
+        for row in 0..height {
+            for col in 0..width {
+                if rng.random_bool(density) {
+                    grid.set(row, col, CellState::Alive);
+                }
+            }
+        }
+        grid
+    }
+
+    /// Counts live neighbors the slow, obvious way: every neighbor beyond an edge that doesn't
+    /// wrap is dead, and every other one is looked up after wrapping its coordinates.
+    fn reference_count(grid: &Grid, row: usize, col: usize) -> u8 {
+        let (width, height) = (grid.width() as isize, grid.height() as isize);
+        let mut count = 0;
+        for dr in -1..=1 {
+            for dc in -1..=1 {
+                if (dr, dc) == (0, 0) {
+                    continue;
+                }
+                let (mut r, mut c) = (row as isize + dr, col as isize + dc);
+                if r < 0 || r >= height {
+                    if !grid.wrap_y() {
+                        continue;
+                    }
+                    r = r.rem_euclid(height);
+                }
+                if c < 0 || c >= width {
+                    if !grid.wrap_x() {
+                        continue;
+                    }
+                    c = c.rem_euclid(width);
+                }
+                let (r, c) = (r as usize, c as usize);
+                let alive = grid.get(r, c) == Some(CellState::Alive)
+                    || (grid.walls_alive() && grid.is_wall(r, c));
+                count += u8::from(alive);
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn count_neighbors_matches_reference_on_random_grids() {
+        let mut rng = StdRng::seed_from_u64(375);
+        for topology in [Topology::Bounded, Topology::Cylinder, Topology::Torus] {
+            for _ in 0..200 {
+                // Include 1- and 2-wide grids, where every cell is on an edge or corner
+                let (width, height) = (rng.random_range(1..=12), rng.random_range(1..=12));
+                let density = rng.random_range(0.0..=1.0);
+                let mut grid = random_grid(&mut rng, width, height, density);
+                grid.set_topology(topology);
+                if rng.random_bool(0.3) {
+                    grid.set_walls_alive(true);
+                    for _ in 0..rng.random_range(0..=4) {
+                        let (row, col) = (rng.random_range(0..height), rng.random_range(0..width));
+                        grid.set(row, col, CellState::Dead);
+                        grid.set_wall(row, col, true);
+                    }
+                }
+
+                for row in 0..height {
+                    for col in 0..width {
+                        assert_eq!(
+                            grid.count_neighbors(row, col),
+                            reference_count(&grid, row, col),
+                            "{topology:?} {width}×{height} at ({row}, {col}):\n{}",
+                            grid.render()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn try_new_rejects_zero_dimensions() {
+        for (width, height) in [(0, 0), (0, 5), (5, 0), (0, usize::MAX), (usize::MAX, 0)] {
+            assert_eq!(Grid::try_new(width, height).unwrap_err(), GridError::Empty);
+            assert_eq!(
+                Grid::try_new_with_limit(width, height, usize::MAX).unwrap_err(),
+                GridError::Empty
+            );
+        }
+        let grid = Grid::try_new(1, 1).unwrap();
+        assert_eq!(grid.dimensions(), (1, 1));
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn try_new_allows_exactly_the_cell_limit() {
+        assert_eq!(
+            Grid::try_new_with_limit(6, 4, 24).unwrap().dimensions(),
+            (6, 4)
+        );
+        assert_eq!(
+            Grid::try_new_with_limit(5, 5, 24).unwrap_err(),
+            GridError::TooLarge {
+                width: 5,
+                height: 5,
+                max_cells: 24
+            }
+        );
+        assert!(matches!(
+            Grid::try_new(4097, 4096),
+            Err(GridError::TooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn dimensions_whose_product_overflows_are_too_large() {
+        let overflowing = [
+            (usize::MAX, 2),
+            (2, usize::MAX),
+            (usize::MAX, usize::MAX),
+            (1 << (usize::BITS / 2), 1 << (usize::BITS / 2)),
+        ];
+        for (width, height) in overflowing {
+            let too_large = GridError::TooLarge {
+                width,
+                height,
+                max_cells: usize::MAX,
+            };
+            assert_eq!(
+                Grid::check_dimensions(width, height, usize::MAX),
+                Err(too_large.clone())
+            );
+            assert_eq!(
+                Grid::try_new_with_limit(width, height, usize::MAX).unwrap_err(),
+                too_large
+            );
+            assert!(matches!(
+                Grid::try_new(width, height),
+                Err(GridError::TooLarge { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn from_bool_matrix_rejects_grids_over_the_limit() {
+        let rows = vec![vec![false; DEFAULT_MAX_CELLS + 1]];
+        assert_eq!(
+            Grid::from_bool_matrix(&rows).unwrap_err(),
+            GridError::TooLarge {
+                width: DEFAULT_MAX_CELLS + 1,
+                height: 1,
+                max_cells: DEFAULT_MAX_CELLS,
+            }
+        );
+    }
+
+    #[test]
+    fn cell_accessors_reject_out_of_bounds_coordinates() {
+        let mut grid = Grid::new(4, 3);
+        grid.set_birth_log(true);
+        grid.set_log_generation(7);
+        // Cells a naive index would alias out of bounds coordinates onto
+        for (row, col) in [(1, 0), (2, 3)] {
+            grid.set(row, col, CellState::Alive);
+            grid.set_since_death(row, col, 0);
+        }
+        grid.age_cells();
+        assert_eq!(grid.born_at(1, 0), Some(7));
+        assert_eq!(grid.age(1, 0), 1);
+
+        for (row, col) in [(0, 4), (3, 0), (2, 4), (usize::MAX, usize::MAX)] {
+            assert_eq!(grid.get(row, col), None);
+            assert_eq!(grid.born_at(row, col), None, "({row}, {col})");
+            assert_eq!(grid.age(row, col), 0, "({row}, {col})");
+            assert_eq!(grid.since_death(row, col), u16::MAX, "({row}, {col})");
+            assert!(!grid.changed_last_step(row, col), "({row}, {col})");
+            grid.set_since_death(row, col, 5);
+        }
+        assert_eq!(grid.since_death(1, 0), 0);
+        assert_eq!(grid.since_death(2, 3), 0);
+
+        // Out of bounds centers count as dead rather than as the cell they'd alias onto
+        for (row, col) in [(0, 4), (3, 0), (2, 4)] {
+            assert_eq!(
+                grid.count_self_and_neighbors(row, col),
+                grid.count_neighbors(row, col),
+                "({row}, {col})"
+            );
+        }
+    }
+
+    #[test]
+    fn walls_never_come_alive() {
+        let mut grid = Grid::new(5, 5);
+        grid.set_wall(2, 2, true);
+        assert_eq!(grid.set(2, 2, CellState::Alive), None);
+        assert_eq!(grid.get(2, 2), Some(CellState::Dead));
+        assert_eq!(grid.set(2, 2, CellState::Dead), Some(CellState::Dead));
+
+        // Only the cells off the wall count as placed
+        let placed = grid.stamp(&[(0, 0), (0, 1), (1, 1), (1, 2)], 1, 1);
+        assert_eq!(placed, 3);
+        assert_eq!(grid.live_count(), 3);
+    }
+
+    #[test]
+    fn bounded_grid_has_no_neighbors_beyond_its_edges() {
+        let mut grid = Grid::new(3, 3);
+        grid.set_topology(Topology::Bounded);
+        for row in 0..3 {
+            for col in 0..3 {
+                grid.set(row, col, CellState::Alive);
+            }
+        }
+        assert_eq!(grid.count_neighbors(0, 0), 3);
+        assert_eq!(grid.count_neighbors(0, 1), 5);
+        assert_eq!(grid.count_neighbors(1, 1), 8);
+        assert_eq!(grid.count_neighbors(2, 2), 3);
+    }
+
+    #[test]
+    fn opposite_edges_are_one_apart_when_wrapping() {
+        let mut grid = Grid::new(10, 6);
+        assert_eq!(grid.wrapped_delta((2, 0), (2, 9)), (0, -1));
+        assert_eq!(grid.wrapped_delta((2, 9), (2, 0)), (0, 1));
+        assert_eq!(grid.wrapped_distance((2, 0), (2, 9)), 1);
+        assert_eq!(grid.wrapped_delta((0, 4), (5, 4)), (-1, 0));
+        assert_eq!(grid.wrapped_distance((0, 0), (5, 9)), 1);
+        // Never more than half the way around
+        assert_eq!(grid.wrapped_delta((0, 0), (3, 5)), (3, 5));
+        assert_eq!(grid.wrapped_delta((0, 0), (4, 6)), (-2, -4));
+
+        grid.set_topology(Topology::Cylinder);
+        assert_eq!(grid.wrapped_distance((2, 0), (2, 9)), 1);
+        assert_eq!(grid.wrapped_delta((0, 4), (5, 4)), (5, 0));
+
+        grid.set_topology(Topology::Bounded);
+        assert_eq!(grid.wrapped_delta((2, 0), (2, 9)), (0, 9));
+        assert_eq!(grid.wrapped_distance((0, 0), (5, 9)), 9);
+    }
+
+    #[test]
+    fn translate_keeps_every_cell_when_wrapping() {
+        let mut rng = StdRng::seed_from_u64(419);
+        let mut grid = random_grid(&mut rng, 12, 9, 0.4);
+        let population = grid.live_count();
+        let mut expected: Vec<_> = grid
+            .iter_alive_cells()
+            .map(|(row, col)| ((row + 9 - 4) % 9, (col + 5) % 12))
+            .collect();
+
+        grid.translate(-4, 5, true);
+        assert_eq!(grid.live_count(), population);
+        let mut moved: Vec<_> = grid.iter_alive_cells().collect();
+        moved.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(moved, expected);
+
+        grid.translate(4, -5, true);
+        grid.translate(27, -36, true);
+        assert_eq!(grid.live_count(), population);
+    }
+
+    #[test]
+    fn translate_drops_cells_moved_past_an_edge_when_clipping() {
+        let mut grid = Grid::new(10, 10);
+        grid.stamp(&[(0, 0), (0, 1), (0, 2)], 4, 5);
+        grid.translate(0, 2, false);
+        assert_eq!(grid.live_count(), 3);
+        grid.translate(0, 1, false);
+        assert_eq!(grid.live_count(), 2);
+        assert_eq!(
+            grid.iter_alive_cells().collect::<Vec<_>>(),
+            [(4, 8), (4, 9)]
+        );
+        grid.translate(-5, 0, false);
+        assert!(grid.is_empty());
+
+        let mut rng = StdRng::seed_from_u64(419);
+        let mut grid = random_grid(&mut rng, 12, 9, 0.4);
+        let population = grid.live_count();
+        grid.translate(3, -2, false);
+        assert!(grid.live_count() < population);
+    }
+
+    #[test]
+    fn shaded_char_darkens_with_density() {
+        // Fills the first `live` cells of the top-left 4×4 block, row by row
+        let block = |live: usize| {
+            let mut grid = Grid::new(8, 8);
+            for i in 0..live {
+                grid.set(i / 4, i % 4, CellState::Alive);
+            }
+            grid.shaded_char(0, 0, 4, 4)
+        };
+        assert_eq!(block(0), ' ');
+        assert_eq!(block(8), '▒');
+        assert_eq!(block(16), '█');
+        // A single cell still shows, and one missing cell keeps a block from looking full
+        assert_eq!(block(1), '░');
+        assert_eq!(block(15), '▓');
+
+        // Half of every block, in each block shape
+        let mut grid = Grid::new(8, 8);
+        for row in 0..8 {
+            for col in (row % 2..8).step_by(2) {
+                grid.set(row, col, CellState::Alive);
+            }
+        }
+        for (block_w, block_h) in [(2, 2), (2, 4), (4, 2), (4, 4)] {
+            assert_eq!(grid.shaded_char(1, 1, block_w, block_h), '▒');
+        }
+        assert_eq!(Grid::new(8, 8).shaded_char(1, 1, 2, 4), ' ');
+
+        // Cells past the edge count as dead
+        let mut grid = Grid::new(6, 6);
+        for row in 0..6 {
+            for col in 0..6 {
+                grid.set(row, col, CellState::Alive);
+            }
+        }
+        assert_eq!(grid.shaded_char(0, 0, 4, 4), '█');
+        assert_eq!(grid.shaded_char(1, 1, 4, 4), '░');
+    }
+
+    #[test]
+    fn glider_wraps_sideways_but_not_vertically_on_a_cylinder() {
+        let glider = [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)];
+        let mut game = crate::game::Game::new((10, 16));
+        game.grid.set_topology(Topology::Cylinder);
+        game.grid.stamp(&glider, 2, 6);
+
+        // Four cells down and right in 16 generations, across the right edge
+        for _ in 0..16 {
+            game.step();
+        }
+        let mut expected: Vec<_> = glider
+            .iter()
+            .map(|&(row, col)| (row + 6, (col + 10) % 10))
+            .collect();
+        expected.sort_unstable();
+        assert_eq!(game.grid.iter_alive_cells().collect::<Vec<_>>(), expected);
+
+        // At the bottom edge it crashes instead of coming back at the top
+        for _ in 0..60 {
+            game.step();
+            assert!(game.grid.iter_alive_cells().all(|(row, _)| row >= 6));
+        }
+        assert!(game.grid.live_count() != glider.len());
+    }
+
+    #[test]
+    fn iter_region_is_clipped_to_the_grid() {
+        let mut grid = Grid::new(6, 4);
+        grid.set(3, 5, CellState::Alive);
+        grid.set(0, 0, CellState::Alive);
+        let coords = |top, left, height, width| {
+            grid.iter_region(top, left, height, width)
+                .map(|(cell, _)| cell)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(coords(1, 2, 2, 2), [(1, 2), (1, 3), (2, 2), (2, 3)]);
+        // Past the bottom-right corner, without wrapping to the top-left
+        assert_eq!(coords(2, 4, 5, 5), [(2, 4), (2, 5), (3, 4), (3, 5)]);
+        let states: Vec<_> = grid
+            .iter_region(2, 4, 5, 5)
+            .map(|(_, state)| state)
+            .collect();
+        assert_eq!(states.iter().filter(|state| state.is_alive()).count(), 1);
+        // Sizes so large their ends overflow
+        assert_eq!(coords(3, 5, usize::MAX, usize::MAX), [(3, 5)]);
+        assert_eq!(coords(0, 0, usize::MAX, usize::MAX).len(), 24);
+        // Empty and entirely outside regions
+        assert!(coords(1, 1, 0, 3).is_empty());
+        assert!(coords(1, 1, 3, 0).is_empty());
+        assert!(coords(4, 0, 2, 2).is_empty());
+        assert!(coords(0, 6, 2, 2).is_empty());
+        assert!(coords(usize::MAX, usize::MAX, 2, 2).is_empty());
+    }
+
+    #[test]
+    fn count_clusters_joins_corners_only_when_diagonal() {
+        // A glider: its top cell touches the rest only at a corner
+        let grid = grid_with(10, 10, &[(4, 5), (5, 6), (6, 4), (6, 5), (6, 6)]);
+        assert_eq!(grid.count_clusters(false), 2);
+        assert_eq!(grid.count_clusters(true), 1);
+
+        // A domino and a lone cell touching only at a corner, plus a distant cell
+        let grid = grid_with(10, 10, &[(1, 1), (1, 2), (2, 3), (8, 8)]);
+        assert_eq!(grid.count_clusters(false), 3);
+        assert_eq!(grid.count_clusters(true), 2);
+    }
+
+    #[test]
+    fn count_clusters_connects_across_the_edges_it_wraps() {
+        // One pair across the left and right edges, one across the top and bottom
+        let mut grid = grid_with(10, 8, &[(3, 0), (3, 9), (0, 5), (7, 5)]);
+        assert_eq!(grid.count_clusters(false), 2);
+
+        grid.set_topology(Topology::Cylinder);
+        assert_eq!(grid.count_clusters(false), 3);
+
+        grid.set_topology(Topology::Bounded);
+        assert_eq!(grid.count_clusters(false), 4);
+
+        // Opposite corners touch diagonally only on a torus
+        let mut grid = grid_with(10, 8, &[(0, 0), (7, 9)]);
+        assert_eq!(grid.count_clusters(false), 2);
+        assert_eq!(grid.count_clusters(true), 1);
+        grid.set_topology(Topology::Bounded);
+        assert_eq!(grid.count_clusters(true), 2);
+    }
+
+    #[test]
+    fn count_clusters_of_an_empty_grid_is_zero() {
+        let mut grid = Grid::new(6, 4);
+        assert_eq!(grid.count_clusters(false), 0);
+        assert_eq!(grid.count_clusters(true), 0);
+
+        // Walls aren't live cells, so they don't make clusters of their own
+        grid.set_wall(1, 1, true);
+        grid.set_walls_alive(true);
+        assert_eq!(grid.count_clusters(true), 0);
+    }
+
+    #[test]
+    fn count_clusters_doesnt_connect_through_walls() {
+        // A row of three with a wall put in the middle
+        let mut grid = grid_with(7, 5, &[(2, 2), (2, 3), (2, 4)]);
+        assert_eq!(grid.count_clusters(false), 1);
+
+        grid.set_wall(2, 3, true);
+        assert_eq!(grid.count_clusters(false), 2);
+        assert_eq!(grid.count_clusters(true), 2);
+        // Even when walls count as live neighbors
+        grid.set_walls_alive(true);
+        assert_eq!(grid.count_clusters(true), 2);
+    }
+
+    #[test]
+    fn neighbor_histogram_counts_live_cells_by_neighbors() {
+        // Every cell of a block has the other three as neighbors
+        let grid = grid_with(6, 6, &[(2, 2), (2, 3), (3, 2), (3, 3)]);
+        assert_eq!(grid.neighbor_histogram(), [0, 0, 0, 4, 0, 0, 0, 0, 0]);
+
+        // A blinker's ends each touch the middle, which touches both
+        let grid = grid_with(6, 6, &[(2, 1), (2, 2), (2, 3)]);
+        assert_eq!(grid.neighbor_histogram(), [0, 2, 1, 0, 0, 0, 0, 0, 0]);
+
+        // Dead cells aren't counted, however many neighbors they have
+        assert_eq!(Grid::new(6, 6).neighbor_histogram(), [0; 9]);
+    }
+
+    #[test]
+    fn neighbor_histogram_follows_wrapping_and_walls() {
+        // A pair across the left and right edges
+        let mut grid = grid_with(6, 6, &[(2, 0), (2, 5)]);
+        assert_eq!(grid.neighbor_histogram(), [0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        grid.set_topology(Topology::Bounded);
+        assert_eq!(grid.neighbor_histogram(), [2, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        // A wall beside a lone cell is a neighbor only while walls count as alive
+        let mut grid = grid_with(6, 6, &[(2, 2)]);
+        grid.set_wall(2, 3, true);
+        grid.set_walls_alive(false);
+        assert_eq!(grid.neighbor_histogram(), [1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        grid.set_walls_alive(true);
+        assert_eq!(grid.neighbor_histogram(), [0, 1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+}
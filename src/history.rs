@@ -0,0 +1,145 @@
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+/// Number of generations kept in a [`PopulationHistory`].
+pub const HISTORY_CAPACITY: usize = 1000;
+
+/// Header row for population history CSV.
+const CSV_HEADER: &str = "generation,population,births,deaths,activity";
+
+/// Population and cell changes recorded for one generation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryEntry {
+    /// Generation the entry describes.
+    pub generation: u64,
+    /// Number of live cells.
+    pub population: usize,
+    /// Number of dead cells that became alive in the step to this generation.
+    pub births: usize,
+    /// Number of live cells that died in the step to this generation.
+    pub deaths: usize,
+}
+
+impl HistoryEntry {
+    /// Returns the total number of cells that changed state.
+    pub fn activity(&self) -> usize {
+        self.births + self.deaths
+    }
+
+    /// Formats the entry as a CSV row matching [`CSV_HEADER`], without a line ending.
+    fn to_csv_row(self) -> String {
+        format!(
+            "{},{},{},{},{}",
+            self.generation,
+            self.population,
+            self.births,
+            self.deaths,
+            self.activity()
+        )
+    }
+}
+
+/// Ring buffer of the most recent generations' population data.
+#[derive(Debug, Clone)]
+pub struct PopulationHistory {
+    /// Entries from oldest to newest.
+    entries: VecDeque<HistoryEntry>,
+    /// Maximum number of entries kept.
+    capacity: usize,
+}
+
+impl PopulationHistory {
+    /// Creates an empty history holding up to `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Adds an entry, dropping the oldest one if the history is full.
+    pub fn push(&mut self, entry: HistoryEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the most recent entry, if any.
+    pub fn latest(&self) -> Option<&HistoryEntry> {
+        self.entries.back()
+    }
+
+    /// Returns an iterator over the entries from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &HistoryEntry> + '_ {
+        self.entries.iter()
+    }
+
+    /// Writes the entries as CSV with a header row.
+    pub fn write_csv(&self, writer: &mut impl Write) -> io::Result<()> {
+        writeln!(writer, "{CSV_HEADER}")?;
+        for entry in &self.entries {
+            writeln!(writer, "{}", entry.to_csv_row())?;
+        }
+        Ok(())
+    }
+
+    /// Writes the entries to a CSV file at `path`, replacing any existing file.
+    pub fn save_csv(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_csv(&mut writer)?;
+        writer.flush()
+    }
+}
+
+impl Default for PopulationHistory {
+    fn default() -> Self {
+        Self::new(HISTORY_CAPACITY)
+    }
+}
+
+/// CSV file that population history is appended to as the simulation runs.
+#[derive(Debug)]
+pub struct CsvLog {
+    /// Destination for CSV rows.
+    writer: BufWriter<File>,
+}
+
+impl CsvLog {
+    /// Creates (or truncates) the CSV file at `path` and writes the header row.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "{CSV_HEADER}")?;
+        writer.flush()?;
+        Ok(Self { writer })
+    }
+
+    /// Appends an entry as a CSV row.
+    ///
+    /// Write errors are ignored so a full disk never interrupts the simulation.
+    pub fn record(&mut self, entry: &HistoryEntry) {
+        let _ = writeln!(self.writer, "{}", entry.to_csv_row()).and_then(|()| self.writer.flush());
+    }
+}
@@ -0,0 +1,501 @@
+use std::sync::OnceLock;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::app::KeyHint;
+
+const NONE: KeyModifiers = KeyModifiers::NONE;
+const ALT: KeyModifiers = KeyModifiers::ALT;
+const CONTROL: KeyModifiers = KeyModifiers::CONTROL;
+
+/// Something a key binding does, carried out by the app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Clear the selection, or quit if there is none.
+    Escape,
+    /// Quit the application.
+    Quit,
+    /// Pause or resume, cancelling any run target or countdown.
+    TogglePause,
+    /// Lengthen the tick interval.
+    SlowDown,
+    /// Shorten the tick interval.
+    SpeedUp,
+    /// Switch to the speed preset numbered by the key, slowest first.
+    SpeedPreset,
+    /// Toggle turbo mode.
+    ToggleTurbo,
+    /// Toggle the cap on generations per second.
+    ToggleRateLimit,
+    /// Randomize the grid.
+    Randomize,
+    /// Restore the board as it was loaded.
+    ResetToLoaded,
+    /// Clear the grid.
+    Clear,
+    /// Make the current board generation 0.
+    ResetGeneration,
+    /// Toggle a few random cells.
+    Perturb,
+    /// Run the configured number of generations, then pause.
+    RunFor,
+    /// Step back a generation in reversible mode.
+    StepBack,
+    /// Toggle the coordinate ruler.
+    ToggleRuler,
+    /// Toggle fading trails.
+    ToggleTrails,
+    /// Toggle highlighting cells that just changed.
+    ToggleSparkle,
+    /// Toggle the statistics overlay.
+    ToggleStats,
+    /// Cycle the width of block cells.
+    CycleCellWidth,
+    /// Lock or unlock the grid size against terminal resizes.
+    ToggleSizeLock,
+    /// Zoom in to a larger rendering of each cell.
+    ZoomIn,
+    /// Zoom out to pack more cells per character.
+    ZoomOut,
+    /// Load the quick-save slot numbered by the key.
+    LoadSlot,
+    /// Save to the quick-save slot numbered by the key.
+    SaveSlot,
+    /// Open the `:` command prompt.
+    OpenPrompt,
+    /// Copy the live cells as RLE.
+    Yank,
+    /// Paste the system clipboard, or the pattern last copied if it can't be read.
+    Paste,
+    /// Show the fingerprint of the live cells.
+    Fingerprint,
+    /// Shrink the drawing brush.
+    ShrinkBrush,
+    /// Grow the drawing brush.
+    GrowBrush,
+    /// Toggle drawing walls instead of cells.
+    ToggleWallMode,
+    /// Toggle inspecting cells under the mouse.
+    ToggleInspect,
+    /// Toggle select mode.
+    ToggleSelectMode,
+    /// Toggle shifting the board with the arrows.
+    ToggleMoveMode,
+    /// Show the help overlay.
+    ShowHelp,
+}
+
+/// Keys a binding responds to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keys {
+    /// A single key.
+    Key(KeyCode),
+    /// The digits `1` to `9`, numbered from 0.
+    Digits,
+    /// The function keys `F1` to `F5`, numbered from 0.
+    Function,
+}
+
+impl Keys {
+    /// Returns the number of `code` within the keys, or `None` if it isn't one of them.
+    fn index(self, code: KeyCode) -> Option<usize> {
+        match (self, code) {
+            (Keys::Key(key), code) if key == code => Some(0),
+            (Keys::Digits, KeyCode::Char(c @ '1'..='9')) => Some(c as usize - '1' as usize),
+            (Keys::Function, KeyCode::F(n @ 1..=5)) => Some(n as usize - 1),
+            _ => None,
+        }
+    }
+
+    /// Returns every key code in the set.
+    #[cfg(test)]
+    pub fn codes(self) -> Vec<KeyCode> {
+        match self {
+            Keys::Key(code) => vec![code],
+            Keys::Digits => ('1'..='9').map(KeyCode::Char).collect(),
+            Keys::Function => (1..=5).map(KeyCode::F).collect(),
+        }
+    }
+
+    /// Returns how the keys are written in help, e.g. `Space`, `1-9`, or `F1-F5`.
+    fn label(self) -> String {
+        match self {
+            Keys::Key(code) => code.to_string(),
+            Keys::Digits => "1-9".to_string(),
+            Keys::Function => "F1-F5".to_string(),
+        }
+    }
+}
+
+/// A set of keys, pressed with exactly the given modifiers, bound to an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    /// Keys that trigger the action.
+    pub keys: Keys,
+    /// Modifiers held with the keys, ignoring Shift for characters.
+    pub modifiers: KeyModifiers,
+    /// Action the keys trigger.
+    pub action: Action,
+}
+
+impl KeyBinding {
+    /// Binds keys pressed without modifiers.
+    const fn plain(keys: Keys, action: Action) -> Self {
+        Self::with(keys, NONE, action)
+    }
+
+    /// Binds keys pressed with modifiers.
+    const fn with(keys: Keys, modifiers: KeyModifiers, action: Action) -> Self {
+        Self {
+            keys,
+            modifiers,
+            action,
+        }
+    }
+
+    /// Returns how the binding is written in help, e.g. `Alt+1-9` or `Ctrl+V`.
+    fn label(&self) -> String {
+        let keys = match self.keys {
+            Keys::Key(KeyCode::Char(c)) if self.modifiers == CONTROL => {
+                c.to_ascii_uppercase().to_string()
+            }
+            keys => keys.label(),
+        };
+        match self.modifiers {
+            CONTROL => format!("Ctrl+{keys}"),
+            ALT => format!("Alt+{keys}"),
+            _ => keys,
+        }
+    }
+}
+
+/// One line of help: related key bindings and what they do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BindingGroup {
+    /// Bindings listed together, e.g. the two directions of a pair of actions.
+    pub bindings: &'static [KeyBinding],
+    /// What the bindings do, as shown in the help overlay.
+    pub description: &'static str,
+    /// Shorter description shown among the status bar hints, if the group is hinted there.
+    pub hint: Option<&'static str>,
+}
+
+impl BindingGroup {
+    /// Returns how the group's keys are written in help, e.g. `Up/Down`.
+    fn label(&self) -> String {
+        join_labels(self.bindings.iter())
+    }
+
+    /// Returns how the group's keys are written among the status bar hints, leaving out keys
+    /// pressed with modifiers to save room.
+    fn hint_label(&self) -> String {
+        join_labels(
+            self.bindings
+                .iter()
+                .filter(|binding| binding.modifiers.is_empty()),
+        )
+    }
+}
+
+/// Joins the labels of bindings with slashes.
+fn join_labels<'a>(bindings: impl Iterator<Item = &'a KeyBinding>) -> String {
+    let labels: Vec<String> = bindings.map(KeyBinding::label).collect();
+    labels.join("/")
+}
+
+/// Shorthand for a group of plain key bindings in [`BINDINGS`].
+const fn key(code: KeyCode, action: Action) -> KeyBinding {
+    KeyBinding::plain(Keys::Key(code), action)
+}
+
+/// Every key binding outside the modes that take over the keys (the command prompt, replays,
+/// and select and move mode), in the order the help lists them.
+///
+/// Both key handling and the help overlay and status bar hints are driven by this table. Hinted
+/// groups come first, most important first, since the status bar drops hints from the end.
+pub const BINDINGS: &[BindingGroup] = &[
+    BindingGroup {
+        bindings: &[key(KeyCode::Char(' '), Action::TogglePause)],
+        description: "toggle pause/resume",
+        hint: Some("pause"),
+    },
+    BindingGroup {
+        bindings: &[
+            key(KeyCode::Esc, Action::Escape),
+            key(KeyCode::Char('q'), Action::Quit),
+            KeyBinding::with(Keys::Key(KeyCode::Char('c')), CONTROL, Action::Quit),
+        ],
+        description: "quit (Esc clears the selection first)",
+        hint: Some("quit"),
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('r'), Action::Randomize)],
+        description: "randomize grid",
+        hint: Some("random"),
+    },
+    BindingGroup {
+        bindings: &[
+            key(KeyCode::Up, Action::SlowDown),
+            key(KeyCode::Down, Action::SpeedUp),
+        ],
+        description: "slow down/speed up",
+        hint: Some("speed"),
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char(':'), Action::OpenPrompt)],
+        description: "open command prompt",
+        hint: Some("command"),
+    },
+    BindingGroup {
+        bindings: &[
+            key(KeyCode::Char('='), Action::ZoomIn),
+            key(KeyCode::Char('-'), Action::ZoomOut),
+        ],
+        description: "zoom in/out",
+        hint: Some("zoom"),
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('?'), Action::ShowHelp)],
+        description: "show this help",
+        hint: Some("help"),
+    },
+    BindingGroup {
+        bindings: &[KeyBinding::plain(Keys::Function, Action::SpeedPreset)],
+        description: "speed preset, slowest to fastest",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('t'), Action::ToggleTurbo)],
+        description: "toggle turbo",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('l'), Action::ToggleRateLimit)],
+        description: "toggle generation rate limit",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('R'), Action::ResetToLoaded)],
+        description: "reset to the loaded board",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('c'), Action::Clear)],
+        description: "clear grid",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('0'), Action::ResetGeneration)],
+        description: "reset generation to 0",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('k'), Action::Perturb)],
+        description: "toggle a few random cells",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('n'), Action::RunFor)],
+        description: "run a few generations, then pause",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char(','), Action::StepBack)],
+        description: "step back (reversible mode only)",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('g'), Action::ToggleRuler)],
+        description: "toggle coordinate ruler",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('x'), Action::ToggleTrails)],
+        description: "toggle trails",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('a'), Action::ToggleSparkle)],
+        description: "toggle highlighting cells that just changed",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('s'), Action::ToggleStats)],
+        description: "toggle statistics overlay",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('w'), Action::CycleCellWidth)],
+        description: "cycle cell width",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('L'), Action::ToggleSizeLock)],
+        description: "toggle locking the grid size",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[KeyBinding::plain(Keys::Digits, Action::LoadSlot)],
+        description: "load quick-save slot",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[KeyBinding::with(Keys::Digits, ALT, Action::SaveSlot)],
+        description: "save quick-save slot",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('y'), Action::Yank)],
+        description: "copy live cells as RLE",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[KeyBinding::with(
+            Keys::Key(KeyCode::Char('v')),
+            CONTROL,
+            Action::Paste,
+        )],
+        description: "paste the copied pattern",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('f'), Action::Fingerprint)],
+        description: "show fingerprint of live cells",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[
+            key(KeyCode::Char('['), Action::ShrinkBrush),
+            key(KeyCode::Char(']'), Action::GrowBrush),
+        ],
+        description: "shrink/grow drawing brush",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('b'), Action::ToggleWallMode)],
+        description: "toggle drawing walls instead of cells",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('i'), Action::ToggleInspect)],
+        description: "toggle inspecting cells with the mouse",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('v'), Action::ToggleSelectMode)],
+        description: "toggle select mode",
+        hint: None,
+    },
+    BindingGroup {
+        bindings: &[key(KeyCode::Char('m'), Action::ToggleMoveMode)],
+        description: "toggle moving the board with the arrows",
+        hint: None,
+    },
+];
+
+/// Mouse controls listed in the help after the keys, as handled by the app's mouse handler.
+const MOUSE_HELP: &[KeyHint] = &[
+    KeyHint::new("wheel", "zoom in/out"),
+    KeyHint::new("left/right drag", "draw/erase cells"),
+];
+
+/// Returns the action bound to a key and the number of the key within its binding's keys (e.g.
+/// `2` for `3` among the digits), or `None` if the key isn't bound.
+///
+/// `modifiers` must already leave out Shift for characters, whose case reflects it (e.g. `R`).
+pub fn lookup(code: KeyCode, modifiers: KeyModifiers) -> Option<(Action, usize)> {
+    BINDINGS
+        .iter()
+        .flat_map(|group| group.bindings)
+        .filter(|binding| binding.modifiers == modifiers)
+        .find_map(|binding| Some((binding.action, binding.keys.index(code)?)))
+}
+
+/// Returns the help overlay's entries: every binding group, then the mouse controls.
+pub fn help() -> &'static [KeyHint] {
+    static HELP: OnceLock<Vec<KeyHint>> = OnceLock::new();
+    HELP.get_or_init(|| {
+        BINDINGS
+            .iter()
+            .map(|group| KeyHint::labelled(group.label(), group.description))
+            .chain(MOUSE_HELP.iter().cloned())
+            .collect()
+    })
+}
+
+/// Returns the status bar hints: the hinted binding groups, most important first.
+pub fn hints() -> &'static [KeyHint] {
+    static HINTS: OnceLock<Vec<KeyHint>> = OnceLock::new();
+    HINTS.get_or_init(|| {
+        BINDINGS
+            .iter()
+            .filter_map(|group| Some(KeyHint::labelled(group.hint_label(), group.hint?)))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_bound_key_looks_up_its_own_action() {
+        let mut seen = Vec::new();
+        for binding in BINDINGS.iter().flat_map(|group| group.bindings) {
+            for (idx, code) in binding.keys.codes().into_iter().enumerate() {
+                assert!(
+                    !seen.contains(&(code, binding.modifiers)),
+                    "{code:?} with {:?} is bound twice",
+                    binding.modifiers
+                );
+                seen.push((code, binding.modifiers));
+                assert_eq!(
+                    lookup(code, binding.modifiers),
+                    Some((binding.action, idx)),
+                    "{code:?} with {:?}",
+                    binding.modifiers
+                );
+            }
+        }
+        assert_eq!(lookup(KeyCode::Char('z'), NONE), None);
+        assert_eq!(lookup(KeyCode::Char('q'), ALT), None);
+    }
+
+    #[test]
+    fn help_lists_every_binding_labelled_with_its_modifiers() {
+        let label = |description: &str| {
+            help()
+                .iter()
+                .find(|hint| hint.action == description)
+                .map(|hint| hint.key.as_ref())
+        };
+        assert_eq!(label("toggle pause/resume"), Some("Space"));
+        assert_eq!(
+            label("quit (Esc clears the selection first)"),
+            Some("Esc/q/Ctrl+C")
+        );
+        assert_eq!(label("slow down/speed up"), Some("Up/Down"));
+        assert_eq!(label("speed preset, slowest to fastest"), Some("F1-F5"));
+        assert_eq!(label("save quick-save slot"), Some("Alt+1-9"));
+        assert_eq!(label("paste the copied pattern"), Some("Ctrl+V"));
+        assert_eq!(label("draw/erase cells"), Some("left/right drag"));
+        assert_eq!(help().len(), BINDINGS.len() + MOUSE_HELP.len());
+
+        let hinted: Vec<_> = hints()
+            .iter()
+            .map(|hint| format!("{} {}", hint.key, hint.action))
+            .collect();
+        assert_eq!(
+            hinted,
+            [
+                "Space pause",
+                "Esc/q quit",
+                "r random",
+                "Up/Down speed",
+                ": command",
+                "=/- zoom",
+                "? help"
+            ]
+        );
+    }
+}
@@ -1,5 +1,21 @@
 pub mod app;
+pub mod autosave;
+mod clipboard;
+mod command;
+pub mod condition;
 mod event;
-mod game;
-mod grid;
+pub mod game;
+pub mod grid;
+pub mod history;
+mod keymap;
+pub mod mask;
+#[cfg(feature = "log")]
+pub mod metrics;
+pub mod patterns;
+pub mod render;
+pub mod replay;
+pub mod rule;
+pub mod scene;
+#[cfg(feature = "stream")]
+pub mod stream;
 mod ui;
@@ -1,18 +1,277 @@
-use ratgol::app::App;
+mod cli;
+
+use std::{fs, io::Read, path::Path};
+
+use color_eyre::eyre::WrapErr;
+use crossterm::{
+    cursor::MoveTo,
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
+    execute,
+};
+use ratatui::{TerminalOptions, Viewport};
+use ratgol::{app::App, autosave, grid::Grid, history::CsvLog, patterns};
+
+use crate::cli::{Args, USAGE};
 
 fn main() -> color_eyre::Result<()> {
     // Initialize error handling
     color_eyre::install().unwrap();
 
+    // Parse arguments
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+    if args.help {
+        println!("{USAGE}");
+        return Ok(());
+    }
+    if let Some((a, b)) = &args.diff {
+        if !diff(a, b)? {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Load the pattern before the terminal is taken over, since it may come from stdin
+    let mut builder = App::builder()
+        .paused(args.paused)
+        .blank(args.blank)
+        .fit(args.fit)
+        .oversize(args.oversize);
+    if let Some(source) = &args.pattern
+        && let Some((name, cells)) = read_pattern(source)?
+    {
+        builder = builder.cells(name, args.transform.apply(&cells));
+    }
+    if args.resume {
+        let path = autosave::default_path().ok_or_else(|| {
+            color_eyre::eyre::eyre!("nothing to resume: neither XDG_STATE_HOME nor HOME is set")
+        })?;
+        let saved = autosave::load(&path).map_err(|e| color_eyre::eyre::eyre!(e))?;
+        builder = builder.resume(saved);
+    }
+    let mut app = builder.build();
+    app.autosave_path = autosave::default_path();
+    if let Some(path) = &args.csv {
+        let csv_log = CsvLog::create(path)
+            .wrap_err_with(|| format!("failed to create CSV file {}", path.display()))?;
+        app.csv_log = Some(csv_log);
+    }
+    #[cfg(feature = "log")]
+    let mut app = attach_metrics_log(app, &args)?;
+    #[cfg(not(feature = "log"))]
+    if let Some(path) = &args.log {
+        color_eyre::eyre::bail!(
+            "cannot log to {}: ratgol was built without the `log` feature",
+            path.display()
+        );
+    }
+    #[cfg(feature = "stream")]
+    match args.stream.as_deref() {
+        // Streaming to stdout replaces the TUI, which would draw over the same output
+        Some("-") => {
+            return ratgol::stream::run_stdout(&mut app.game)
+                .wrap_err("failed to stream to stdout");
+        }
+        Some(addr) => {
+            let server = ratgol::stream::StreamServer::bind(addr)
+                .wrap_err_with(|| format!("failed to listen on {addr}"))?;
+            app.stream = Some(server);
+        }
+        None => {}
+    }
+    #[cfg(not(feature = "stream"))]
+    if args.stream.is_some() {
+        color_eyre::eyre::bail!("cannot stream: ratgol was built without the `stream` feature");
+    }
+
     // Initialize terminal
-    let terminal = ratatui::init();
+    let terminal = if args.no_altscreen {
+        // Draw over the normal screen so the last frame stays in the scrollback
+        let (_, height) = crossterm::terminal::size()?;
+        ratatui::init_with_options(TerminalOptions {
+            viewport: Viewport::Inline(height),
+        })
+    } else {
+        ratatui::init()
+    };
+    execute!(std::io::stdout(), EnableMouseCapture, EnableBracketedPaste)?;
+    install_panic_hook();
 
-    // Create and run the app
-    let app = App::new();
+    // Run the app
     let result = app.run(terminal);
 
     // Restore terminal
     ratatui::restore();
+    execute!(
+        std::io::stdout(),
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )?;
+    if args.no_altscreen {
+        // Leave the shell prompt below the last frame rather than wherever drawing stopped
+        let (_, height) = crossterm::terminal::size()?;
+        execute!(std::io::stdout(), MoveTo(0, height.saturating_sub(1)))?;
+        println!();
+    }
+    if let Some(e) = &app.autosave_error {
+        eprintln!("warning: {e}");
+    }
 
     result
 }
+
+/// A pattern's name and live cells as (row, col) offsets.
+type NamedCells = (String, Vec<(usize, usize)>);
+
+/// Reads the starting pattern named on the command line as its title and live cells.
+///
+/// `source` is a built-in or bundled pattern name, a path to an RLE or plaintext file, or `-` to
+/// read from stdin. Returns `None` if stdin is empty.
+fn read_pattern(source: &str) -> color_eyre::Result<Option<NamedCells>> {
+    if let Some(pattern) = patterns::find(source) {
+        return Ok(Some((pattern.name.to_string(), pattern.cells.to_vec())));
+    }
+    if let Ok(cells) = patterns::load_bundled(source) {
+        return Ok(Some((source.to_lowercase(), cells)));
+    }
+
+    let (name, text) = if source == "-" {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .wrap_err("failed to read pattern from stdin")?;
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        ("stdin".to_string(), text)
+    } else {
+        let path = Path::new(source);
+        let text = fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read pattern {}", path.display()))?;
+        let name = path.file_stem().unwrap_or(path.as_os_str());
+        (name.to_string_lossy().into_owned(), text)
+    };
+
+    let cells = patterns::parse_pattern(&text)
+        .map_err(|e| color_eyre::eyre::eyre!("invalid pattern {source}: {e}"))?;
+    Ok(Some((name, cells)))
+}
+
+/// Compares two patterns, printing whether they match and, if not, the cells that differ.
+///
+/// Cells are compared with both patterns' live cells aligned at the top-left, so patterns that
+/// only differ by position match. Returns true if they match.
+fn diff(a: &str, b: &str) -> color_eyre::Result<bool> {
+    let a_grid = pattern_grid(a)?;
+    let b_grid = pattern_grid(b)?;
+
+    if a_grid == b_grid {
+        println!("{a} and {b} match (fingerprint {})", a_grid.fingerprint());
+        return Ok(true);
+    }
+
+    let height = a_grid.height().max(b_grid.height());
+    let width = a_grid.width().max(b_grid.width());
+    let alive = |grid: &Grid, row, col| grid.get(row, col).is_some_and(|state| state.is_alive());
+    let differing: Vec<_> = (0..height)
+        .flat_map(|row| (0..width).map(move |col| (row, col)))
+        .filter(|&(row, col)| alive(&a_grid, row, col) != alive(&b_grid, row, col))
+        .collect();
+
+    let cells = if differing.len() == 1 {
+        "cell"
+    } else {
+        "cells"
+    };
+    println!("{a} and {b} differ in {} {cells}:", differing.len());
+    for (row, col) in differing {
+        let only_in = if alive(&a_grid, row, col) { a } else { b };
+        println!("  ({row}, {col}) only in {only_in}");
+    }
+    Ok(false)
+}
+
+/// Reads a pattern as for the command line into a grid that just fits it, with the live cells'
+/// bounding box at the top-left corner.
+fn pattern_grid(source: &str) -> color_eyre::Result<Grid> {
+    let mut cells = read_pattern(source)?.map_or_else(Vec::new, |(_, cells)| cells);
+    let top = cells.iter().map(|&(row, _)| row).min().unwrap_or(0);
+    let left = cells.iter().map(|&(_, col)| col).min().unwrap_or(0);
+    for (row, col) in &mut cells {
+        *row -= top;
+        *col -= left;
+    }
+
+    let (height, width) = patterns::bounding_size(&cells);
+    let mut grid = Grid::try_new(width.max(1), height.max(1))
+        .map_err(|e| color_eyre::eyre::eyre!("invalid pattern {source}: {e}"))?;
+    grid.stamp(&cells, 0, 0);
+    Ok(grid)
+}
+
+/// Chains a panic hook that turns off mouse capture and bracketed paste before running the
+/// previous hooks.
+///
+/// `ratatui::init` already installs a hook that leaves raw mode and the alternate screen ahead of
+/// color_eyre's panic report, but it doesn't know about the extra modes enabled here.
+fn install_panic_hook() {
+    chain_panic_hook(|| {
+        let _ = execute!(
+            std::io::stdout(),
+            DisableMouseCapture,
+            DisableBracketedPaste
+        );
+    });
+}
+
+/// Chains a panic hook that runs `restore` before the previous hooks.
+fn chain_panic_hook(restore: impl Fn() + Send + Sync + 'static) {
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore();
+        hook(info);
+    }));
+}
+
+/// Opens the throughput log requested on the command line, if any, and attaches it to the app.
+#[cfg(feature = "log")]
+fn attach_metrics_log(mut app: App, args: &Args) -> color_eyre::Result<App> {
+    use ratgol::metrics::{DEFAULT_LOG_INTERVAL, MetricsLog};
+
+    if let Some(path) = &args.log {
+        let interval = args.log_every.unwrap_or(DEFAULT_LOG_INTERVAL);
+        let log = MetricsLog::create(path, interval)
+            .wrap_err_with(|| format!("failed to create log file {}", path.display()))?;
+        app.metrics_log = Some(log);
+    }
+    Ok(app)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[test]
+    fn panic_hook_restores_the_terminal_before_earlier_hooks() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let previous = std::panic::take_hook();
+        let log = Arc::clone(&calls);
+        std::panic::set_hook(Box::new(move |_| log.lock().unwrap().push("earlier hook")));
+        let log = Arc::clone(&calls);
+        chain_panic_hook(move || log.lock().unwrap().push("restore"));
+
+        let result = std::panic::catch_unwind(|| panic!("boom"));
+        drop(std::panic::take_hook());
+        std::panic::set_hook(previous);
+
+        assert!(result.is_err());
+        assert_eq!(*calls.lock().unwrap(), ["restore", "earlier hook"]);
+    }
+}
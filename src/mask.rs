@@ -0,0 +1,99 @@
+/// A set of cells that an operation is restricted to, with one flag per cell of a grid.
+///
+/// Masks generalize the rectangular regions taken by methods like [`Game::step_region`] to any
+/// shape. Flagged cells beyond the edges of the grid a mask is applied to are ignored.
+///
+/// [`Game::step_region`]: crate::game::Game::step_region
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    width: usize,
+    height: usize,
+    /// Whether each cell is included, in row-major order.
+    cells: Vec<bool>,
+}
+
+impl Mask {
+    /// Creates an empty mask of the given size, including no cells.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![false; width * height],
+        }
+    }
+
+    /// Creates a mask including every cell within `radius` straight-line distance of the cell
+    /// at (`row`, `col`).
+    ///
+    /// The disk is clipped to the mask's bounds rather than wrapping around them.
+    pub fn disk(width: usize, height: usize, row: usize, col: usize, radius: usize) -> Self {
+        let mut mask = Self::new(width, height);
+        let row_range = row.saturating_sub(radius)..=row.saturating_add(radius);
+        let col_range = col.saturating_sub(radius)..=col.saturating_add(radius);
+        for r in row_range.clone() {
+            for c in col_range.clone() {
+                let (dr, dc) = (r.abs_diff(row), c.abs_diff(col));
+                if dr * dr + dc * dc <= radius * radius {
+                    mask.set(r, c, true);
+                }
+            }
+        }
+        mask
+    }
+
+    /// Creates a mask including the given cells, such as a pattern's live cells, offset by
+    /// (`row`, `col`).
+    ///
+    /// Cells that fall outside the mask are skipped.
+    pub fn from_cells(
+        width: usize,
+        height: usize,
+        cells: &[(usize, usize)],
+        row: usize,
+        col: usize,
+    ) -> Self {
+        let mut mask = Self::new(width, height);
+        for &(r, c) in cells {
+            mask.set(row + r, col + c, true);
+        }
+        mask
+    }
+
+    /// Returns the mask's width in cells.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the mask's height in cells.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns true if the cell at the given coordinates is included.
+    ///
+    /// Coordinates out of bounds are never included.
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width && self.cells[row * self.width + col]
+    }
+
+    /// Includes or excludes a cell, doing nothing if coordinates are out of bounds.
+    pub fn set(&mut self, row: usize, col: usize, included: bool) {
+        if row < self.height && col < self.width {
+            self.cells[row * self.width + col] = included;
+        }
+    }
+
+    /// Returns the number of cells included.
+    pub fn count(&self) -> usize {
+        self.cells.iter().filter(|&&included| included).count()
+    }
+
+    /// Returns an iterator over the (row, col) of every included cell, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|&(_, &included)| included)
+            .map(|(idx, _)| (idx / self.width, idx % self.width))
+    }
+}
@@ -0,0 +1,74 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{game::Game, grid::GridBackend};
+
+/// Default number of generations between log records.
+pub const DEFAULT_LOG_INTERVAL: u64 = 100;
+
+/// Periodic simulation throughput log written to a file.
+///
+/// Every `interval` generations a single `key=value` line is appended with the generation,
+/// population, average step duration, and generations per second since the previous record.
+#[derive(Debug)]
+pub struct MetricsLog {
+    /// Destination for log records.
+    writer: BufWriter<File>,
+    /// Number of generations between records.
+    interval: u64,
+    /// Time of the previous record (or of creation).
+    window_start: Instant,
+    /// Total time spent stepping since the previous record.
+    step_time: Duration,
+    /// Number of steps since the previous record.
+    steps: u64,
+}
+
+impl MetricsLog {
+    /// Creates (or truncates) the log file at `path`, recording every `interval` generations.
+    pub fn create(path: &Path, interval: u64) -> io::Result<Self> {
+        assert!(interval > 0, "Log interval must be positive and non-zero");
+
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            interval,
+            window_start: Instant::now(),
+            step_time: Duration::ZERO,
+            steps: 0,
+        })
+    }
+
+    /// Accounts for one simulation step, writing a record if the generation is due.
+    ///
+    /// Write errors are ignored so a full disk never interrupts the simulation.
+    pub fn record(&mut self, game: &Game, step_duration: Duration) {
+        self.step_time += step_duration;
+        self.steps += 1;
+        if !game.generation.is_multiple_of(self.interval) {
+            return;
+        }
+
+        let elapsed = self.window_start.elapsed().as_secs_f64();
+        let gens_per_sec = if elapsed > 0.0 {
+            self.steps as f64 / elapsed
+        } else {
+            0.0
+        };
+        let avg_step_us = self.step_time.as_micros() / u128::from(self.steps);
+        let _ = writeln!(
+            self.writer,
+            "generation={} population={} avg_step_us={avg_step_us} gens_per_sec={gens_per_sec:.1}",
+            game.generation,
+            game.grid.live_count(),
+        )
+        .and_then(|()| self.writer.flush());
+
+        self.window_start = Instant::now();
+        self.step_time = Duration::ZERO;
+        self.steps = 0;
+    }
+}
@@ -0,0 +1,908 @@
+use std::{fmt, str::FromStr, sync::OnceLock};
+
+use crate::{
+    grid::{CellState, DEFAULT_MAX_CELLS, Grid, GridBackend},
+    rule::Rule,
+};
+
+/// Maximum line length of encoded RLE, as recommended by the format.
+const RLE_LINE_WIDTH: usize = 70;
+
+/// A named pattern from the built-in library.
+#[derive(Debug, Clone, Copy)]
+pub struct Pattern {
+    /// Common name of the pattern.
+    pub name: &'static str,
+    /// Live cells as (row, col) offsets from the pattern's top-left corner.
+    pub cells: &'static [(usize, usize)],
+}
+
+impl Pattern {
+    /// Returns the (height, width) of the pattern's bounding box.
+    pub fn size(&self) -> (usize, usize) {
+        bounding_size(self.cells)
+    }
+}
+
+/// Looks up a pattern in the built-in library by name, ignoring case.
+///
+/// For patterns with several phases, the first listed phase is returned.
+pub fn find(name: &str) -> Option<&'static Pattern> {
+    LIBRARY.iter().find(|p| p.name.eq_ignore_ascii_case(name))
+}
+
+/// Pattern files embedded from `assets/patterns`, as (name, contents) in alphabetical order.
+///
+/// These cover larger patterns that would be awkward to list cell by cell in [`LIBRARY`].
+const BUNDLED: &[(&str, &str)] = &[
+    ("acorn", include_str!("../assets/patterns/acorn.cells")),
+    ("diehard", include_str!("../assets/patterns/diehard.cells")),
+    (
+        "gosper-glider-gun",
+        include_str!("../assets/patterns/gosper-glider-gun.rle"),
+    ),
+    ("lwss", include_str!("../assets/patterns/lwss.cells")),
+    (
+        "pentadecathlon",
+        include_str!("../assets/patterns/pentadecathlon.cells"),
+    ),
+    ("pulsar", include_str!("../assets/patterns/pulsar.cells")),
+    (
+        "r-pentomino",
+        include_str!("../assets/patterns/r-pentomino.cells"),
+    ),
+    (
+        "simkin-glider-gun",
+        include_str!("../assets/patterns/simkin-glider-gun.rle"),
+    ),
+];
+
+/// Returns the names of the pattern files bundled with ratgol, in alphabetical order.
+pub fn list_bundled() -> impl Iterator<Item = &'static str> {
+    BUNDLED.iter().map(|&(name, _)| name)
+}
+
+/// Parses a bundled pattern file by name, ignoring case, into its live cells as (row, col)
+/// offsets.
+pub fn load_bundled(name: &str) -> Result<Vec<(usize, usize)>, String> {
+    let (_, text) = BUNDLED
+        .iter()
+        .find(|(bundled, _)| bundled.eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("unknown bundled pattern: {name}"))?;
+    parse_pattern(text)
+}
+
+/// Parses a pattern in plaintext (`.cells`) format.
+///
+/// Lines starting with `!` are comments, `O` or `*` marks a live cell, and any other character
+/// marks a dead cell. Returns the live cells as (row, col) offsets.
+pub fn parse_plaintext(text: &str) -> Result<Vec<(usize, usize)>, String> {
+    let cells: Vec<(usize, usize)> = text
+        .lines()
+        .filter(|line| !line.starts_with('!'))
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == 'O' || c == '*')
+                .map(move |(col, _)| (row, col))
+        })
+        .collect();
+
+    if cells.is_empty() {
+        return Err("pattern has no live cells".to_string());
+    }
+    Ok(cells)
+}
+
+/// Parses a pattern in run-length encoded (`.rle`) format.
+///
+/// Lines starting with `#` and the `x = ..., y = ...` header are skipped. In the body, `b` or `.`
+/// marks dead cells, any other letter marks live cells, `$` ends a row, and `!` ends the pattern;
+/// each may be preceded by a repeat count. Returns the live cells as (row, col) offsets.
+///
+/// Fails if a position overflows or the pattern has more than [`DEFAULT_MAX_CELLS`] live cells,
+/// so untrusted input can't exhaust memory before the pattern is placed.
+pub fn from_rle(text: &str) -> Result<Vec<(usize, usize)>, String> {
+    let body = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .skip_while(|line| line.is_empty())
+        .enumerate()
+        .filter(|&(idx, line)| !(idx == 0 && line.starts_with('x')))
+        .map(|(_, line)| line);
+
+    let mut cells = Vec::new();
+    let (mut row, mut col) = (0, 0);
+    let mut count: Option<usize> = None;
+    'body: for line in body {
+        for c in line.chars() {
+            match c {
+                '0'..='9' => {
+                    let digit = c as usize - '0' as usize;
+                    count = count
+                        .unwrap_or(0)
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(digit));
+                    if count.is_none() {
+                        return Err("run count is too large".to_string());
+                    }
+                    continue;
+                }
+                'b' | '.' => col = advance(col, count)?,
+                '$' => {
+                    row = advance(row, count)?;
+                    col = 0;
+                }
+                '!' => break 'body,
+                c if c.is_ascii_alphabetic() => {
+                    let end = advance(col, count)?;
+                    if end - col > DEFAULT_MAX_CELLS - cells.len() {
+                        return Err(format!(
+                            "pattern has more than {DEFAULT_MAX_CELLS} live cells"
+                        ));
+                    }
+                    cells.extend((col..end).map(|col| (row, col)));
+                    col = end;
+                }
+                c if c.is_whitespace() => {}
+                c => return Err(format!("invalid character in RLE: '{c}'")),
+            }
+            count = None;
+        }
+    }
+
+    if cells.is_empty() {
+        return Err("pattern has no live cells".to_string());
+    }
+    Ok(cells)
+}
+
+/// Moves an RLE position forward by a run of `count` (1 if absent), failing on overflow.
+fn advance(pos: usize, count: Option<usize>) -> Result<usize, String> {
+    pos.checked_add(count.unwrap_or(1))
+        .ok_or_else(|| "pattern position is too large".to_string())
+}
+
+/// Encodes cells as a run-length encoded (`.rle`) pattern, including the `x = ..., y = ...`
+/// header with the given rule.
+///
+/// Cells are taken as (row, col) offsets; the pattern is not moved to the origin.
+pub fn to_rle(cells: &[(usize, usize)], rule: &Rule) -> String {
+    let (height, width) = bounding_size(cells);
+    let mut sorted = cells.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    // Runs of (count, tag), merging adjacent runs of the same tag
+    let mut runs: Vec<(usize, char)> = Vec::new();
+    let mut push = |count: usize, tag: char| match runs.last_mut() {
+        Some((n, last)) if *last == tag => *n += count,
+        _ if count > 0 => runs.push((count, tag)),
+        _ => {}
+    };
+    let (mut row, mut col) = (0, 0);
+    for &(r, c) in &sorted {
+        if r > row {
+            push(r - row, '$');
+            (row, col) = (r, 0);
+        }
+        push(c - col, 'b');
+        push(1, 'o');
+        col = c + 1;
+    }
+    push(1, '!');
+
+    let mut rle = format!("x = {width}, y = {height}, rule = {rule}\n");
+    let mut line_len = 0;
+    for (count, tag) in runs {
+        let token = match count {
+            1 => tag.to_string(),
+            n => format!("{n}{tag}"),
+        };
+        if line_len + token.len() > RLE_LINE_WIDTH {
+            rle.push('\n');
+            line_len = 0;
+        }
+        line_len += token.len();
+        rle.push_str(&token);
+    }
+    rle.push('\n');
+    rle
+}
+
+/// Parses a pattern in either RLE or plaintext format, telling them apart by content.
+///
+/// Text whose first line that isn't a `#` comment starts with an `x = ...` header is read as
+/// RLE, and anything else as plaintext.
+pub fn parse_pattern(text: &str) -> Result<Vec<(usize, usize)>, String> {
+    let is_rle = text
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .is_some_and(|line| line.starts_with('x'));
+    if is_rle {
+        from_rle(text)
+    } else {
+        parse_plaintext(text)
+    }
+}
+
+/// Rotates cells clockwise by `quarter_turns` × 90° and moves them back to the origin.
+pub fn rotate(cells: &[(usize, usize)], quarter_turns: u8) -> Vec<(usize, usize)> {
+    let mut rotated = cells.to_vec();
+    for _ in 0..quarter_turns % 4 {
+        let height = rotated.iter().map(|&(r, _)| r + 1).max().unwrap_or(0);
+        // (row, col) -> (col, height - 1 - row) turns the pattern 90° clockwise
+        rotated = rotated.iter().map(|&(r, c)| (c, height - 1 - r)).collect();
+    }
+    rotated
+}
+
+/// Mirrors cells left to right, or top to bottom if `vertical`, within their bounding box.
+pub fn flip(cells: &[(usize, usize)], vertical: bool) -> Vec<(usize, usize)> {
+    let (height, width) = bounding_size(cells);
+    cells
+        .iter()
+        .map(|&(r, c)| {
+            if vertical {
+                (height - 1 - r, c)
+            } else {
+                (r, width - 1 - c)
+            }
+        })
+        .collect()
+}
+
+/// A rotation and reflection applied to a pattern before it's placed, e.g. to aim a spaceship.
+///
+/// Written as `r0`, `r90`, `r180`, or `r270` for a clockwise rotation and `fh` or `fv` for a
+/// horizontal or vertical flip, joined with `+` to combine them (e.g. `fh+r90`). Flips are applied
+/// before the rotation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Transform {
+    /// Number of 90° clockwise turns.
+    pub quarter_turns: u8,
+    /// Whether to mirror the pattern left to right.
+    pub flip_horizontal: bool,
+    /// Whether to mirror the pattern top to bottom.
+    pub flip_vertical: bool,
+}
+
+impl Transform {
+    /// A rotation by `quarter_turns` × 90° clockwise, without flips.
+    pub fn rotation(quarter_turns: u8) -> Self {
+        Self {
+            quarter_turns: quarter_turns % 4,
+            ..Self::default()
+        }
+    }
+
+    /// Applies the transform to cells, leaving the result anchored at the origin.
+    pub fn apply(&self, cells: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let mut cells = cells.to_vec();
+        if self.flip_horizontal {
+            cells = flip(&cells, false);
+        }
+        if self.flip_vertical {
+            cells = flip(&cells, true);
+        }
+        rotate(&cells, self.quarter_turns)
+    }
+}
+
+impl FromStr for Transform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut transform = Self::default();
+        for part in s.split('+').map(str::trim) {
+            match part {
+                "r0" => transform.quarter_turns = 0,
+                "r90" => transform.quarter_turns = 1,
+                "r180" => transform.quarter_turns = 2,
+                "r270" => transform.quarter_turns = 3,
+                "fh" => transform.flip_horizontal = true,
+                "fv" => transform.flip_vertical = true,
+                _ => return Err(format!("unknown transform: {part}")),
+            }
+        }
+        Ok(transform)
+    }
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if self.flip_horizontal {
+            parts.push("fh".to_string());
+        }
+        if self.flip_vertical {
+            parts.push("fv".to_string());
+        }
+        if !self.quarter_turns.is_multiple_of(4) || parts.is_empty() {
+            parts.push(format!("r{}", u16::from(self.quarter_turns % 4) * 90));
+        }
+        write!(f, "{}", parts.join("+"))
+    }
+}
+
+/// Returns the (height, width) of the bounding box of cells anchored at the origin.
+pub fn bounding_size(cells: &[(usize, usize)]) -> (usize, usize) {
+    let height = cells.iter().map(|&(r, _)| r + 1).max().unwrap_or(0);
+    let width = cells.iter().map(|&(_, c)| c + 1).max().unwrap_or(0);
+    (height, width)
+}
+
+/// Built-in library of small, well-known patterns.
+///
+/// Oscillators and spaceships list each phase that differs under rotation and reflection.
+pub const LIBRARY: &[Pattern] = &[
+    // Still lifes
+    Pattern {
+        name: "block",
+        cells: &[(0, 0), (0, 1), (1, 0), (1, 1)],
+    },
+    Pattern {
+        name: "beehive",
+        cells: &[(0, 1), (0, 2), (1, 0), (1, 3), (2, 1), (2, 2)],
+    },
+    Pattern {
+        name: "loaf",
+        cells: &[(0, 1), (0, 2), (1, 0), (1, 3), (2, 1), (2, 3), (3, 2)],
+    },
+    Pattern {
+        name: "boat",
+        cells: &[(0, 0), (0, 1), (1, 0), (1, 2), (2, 1)],
+    },
+    Pattern {
+        name: "ship",
+        cells: &[(0, 0), (0, 1), (1, 0), (1, 2), (2, 1), (2, 2)],
+    },
+    Pattern {
+        name: "tub",
+        cells: &[(0, 1), (1, 0), (1, 2), (2, 1)],
+    },
+    Pattern {
+        name: "pond",
+        cells: &[
+            (0, 1),
+            (0, 2),
+            (1, 0),
+            (1, 3),
+            (2, 0),
+            (2, 3),
+            (3, 1),
+            (3, 2),
+        ],
+    },
+    // Oscillators
+    Pattern {
+        name: "blinker",
+        cells: &[(0, 0), (0, 1), (0, 2)],
+    },
+    Pattern {
+        name: "toad",
+        cells: &[(0, 1), (0, 2), (0, 3), (1, 0), (1, 1), (1, 2)],
+    },
+    Pattern {
+        name: "toad",
+        cells: &[(0, 2), (1, 0), (1, 3), (2, 0), (2, 3), (3, 1)],
+    },
+    Pattern {
+        name: "beacon",
+        cells: &[
+            (0, 0),
+            (0, 1),
+            (1, 0),
+            (1, 1),
+            (2, 2),
+            (2, 3),
+            (3, 2),
+            (3, 3),
+        ],
+    },
+    Pattern {
+        name: "beacon",
+        cells: &[(0, 0), (0, 1), (1, 0), (2, 3), (3, 2), (3, 3)],
+    },
+    // Spaceships
+    Pattern {
+        name: "glider",
+        cells: &[(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)],
+    },
+    Pattern {
+        name: "glider",
+        cells: &[(0, 0), (0, 2), (1, 1), (1, 2), (2, 1)],
+    },
+];
+
+/// Identifies the grid's live cells as a pattern from the built-in library.
+///
+/// Matching ignores position, rotation, and reflection, but the whole grid must consist of
+/// the single pattern. Returns `None` if the grid is empty or nothing matches.
+pub fn identify(grid: &Grid) -> Option<&'static str> {
+    let max_cells = LIBRARY.iter().map(|p| p.cells.len()).max()?;
+    if grid.is_empty() || grid.live_count() > max_cells {
+        return None;
+    }
+
+    let live: Vec<(usize, usize)> = grid.iter_alive_cells().collect();
+    let signature = canonicalize(&live);
+
+    LIBRARY
+        .iter()
+        .filter(|p| p.cells.len() == live.len())
+        .find(|p| canonicalize(p.cells) == signature)
+        .map(|p| p.name)
+}
+
+/// Well-known oscillators under Conway's rule, as (name, period, one phase in plaintext format).
+///
+/// The other phases are found by running the given one, so each needs listing only once.
+const OSCILLATORS: &[(&str, u64, &str)] = &[
+    ("blinker", 2, "OOO"),
+    ("toad", 2, ".OOO\nOOO."),
+    ("beacon", 2, "OO..\nOO..\n..OO\n..OO"),
+    ("pulsar", 3, include_str!("../assets/patterns/pulsar.cells")),
+    (
+        "pentadecathlon",
+        15,
+        include_str!("../assets/patterns/pentadecathlon.cells"),
+    ),
+];
+
+/// Empty cells kept around an oscillator while running its phases, so it never reaches an edge.
+const OSCILLATOR_MARGIN: usize = 8;
+
+/// A well-known oscillator with every one of its phases.
+struct KnownOscillator {
+    /// Common name of the oscillator.
+    name: &'static str,
+    /// Generations between repeats.
+    period: u64,
+    /// Live cells of each phase, in canonical form.
+    phases: Vec<Vec<(usize, usize)>>,
+    /// How far apart, in rows or columns, live cells of the same phase can be. Some phases
+    /// break into pieces that stay tied together by the cells between them coming back to life.
+    reach: usize,
+}
+
+/// Returns the oscillators in [`OSCILLATORS`] with their phases, worked out on first use.
+fn known_oscillators() -> &'static [KnownOscillator] {
+    static KNOWN: OnceLock<Vec<KnownOscillator>> = OnceLock::new();
+    KNOWN.get_or_init(|| {
+        OSCILLATORS
+            .iter()
+            .map(|&(name, period, text)| {
+                let cells = parse_plaintext(text).expect("built-in oscillators are valid");
+                let phases = oscillator_phases(&cells, period);
+                let reach = phases.iter().map(|phase| reach(phase)).max().unwrap_or(0);
+                KnownOscillator {
+                    name,
+                    period,
+                    phases,
+                    // Cells with one dead cell between them still affect each other
+                    reach: reach.max(2),
+                }
+            })
+            .collect()
+    })
+}
+
+/// Names an oscillator with the given period on the grid.
+///
+/// The grid's live cells are split into groups close enough to affect each other, so an
+/// oscillator's moving cells and any still parts touching them form one group, and each group is
+/// compared against every phase of the known oscillators. Groups are as wide as the oscillator's
+/// own phases reach, so a pentadecathlon that has split in two is still seen whole. Separate still
+/// lifes and debris elsewhere on the grid don't get in the way. Like [`identify`], matching ignores
+/// position, rotation, and reflection. The phases are those under Conway's rule, so callers should
+/// only ask about a grid running it. Returns `None` if no group matches.
+pub fn identify_oscillator(grid: &Grid, period: u64) -> Option<&'static str> {
+    known_oscillators()
+        .iter()
+        .filter(|oscillator| oscillator.period == period)
+        .find(|oscillator| {
+            let max_cells = oscillator.phases.iter().map(Vec::len).max().unwrap_or(0);
+            interacting_groups(grid, oscillator.reach)
+                .iter()
+                .filter(|group| group.len() <= max_cells)
+                .any(|group| oscillator.phases.contains(&canonicalize(group)))
+        })
+        .map(|oscillator| oscillator.name)
+}
+
+/// Returns the smallest distance, in rows or columns, that links all `cells` into one group.
+fn reach(cells: &[(usize, usize)]) -> usize {
+    let distance =
+        |(r1, c1): (usize, usize), (r2, c2): (usize, usize)| r1.abs_diff(r2).max(c1.abs_diff(c2));
+    // Prim's algorithm: the longest link needed to bring in each cell, nearest first
+    let Some((&first, rest)) = cells.split_first() else {
+        return 0;
+    };
+    let mut nearest: Vec<((usize, usize), usize)> = rest
+        .iter()
+        .map(|&cell| (cell, distance(first, cell)))
+        .collect();
+    let mut reach = 0;
+    while !nearest.is_empty() {
+        let closest = (0..nearest.len())
+            .min_by_key(|&i| nearest[i].1)
+            .expect("nearest isn't empty");
+        let (cell, link) = nearest.swap_remove(closest);
+        reach = reach.max(link);
+        for (other, best) in &mut nearest {
+            *best = (*best).min(distance(cell, *other));
+        }
+    }
+    reach
+}
+
+/// Splits the grid's live cells into groups, linking cells up to `reach` rows or columns apart.
+///
+/// Each group's cells are (row, col) offsets from its top-left corner. Groups follow the grid's
+/// wrapping, so one straddling an edge that wraps stays in one piece.
+fn interacting_groups(grid: &Grid, reach: usize) -> Vec<Vec<(usize, usize)>> {
+    let width = grid.width();
+    let mut visited = vec![false; width * grid.height()];
+    let mut groups = Vec::new();
+    for (row, col) in grid.iter_alive_cells() {
+        if std::mem::replace(&mut visited[row * width + col], true) {
+            continue;
+        }
+        // Positions are relative to the first cell and not wrapped, so the group's shape
+        // survives crossing an edge
+        let mut group = vec![(0, 0)];
+        let mut stack = vec![((row, col), (0, 0))];
+        while let Some(((row, col), (y, x))) = stack.pop() {
+            let reach = reach as isize;
+            for dr in -reach..=reach {
+                for dc in -reach..=reach {
+                    let Some((row, col)) = grid.resolve(row as isize + dr, col as isize + dc)
+                    else {
+                        continue;
+                    };
+                    if grid.get(row, col) == Some(CellState::Alive)
+                        && !std::mem::replace(&mut visited[row * width + col], true)
+                    {
+                        group.push((y + dr, x + dc));
+                        stack.push(((row, col), (y + dr, x + dc)));
+                    }
+                }
+            }
+        }
+
+        let top = group.iter().map(|&(y, _)| y).min().unwrap_or(0);
+        let left = group.iter().map(|&(_, x)| x).min().unwrap_or(0);
+        groups.push(
+            group
+                .into_iter()
+                .map(|(y, x)| ((y - top) as usize, (x - left) as usize))
+                .collect(),
+        );
+    }
+    groups
+}
+
+/// Runs `cells` under Conway's rule for `period` generations, returning each generation in
+/// canonical form.
+fn oscillator_phases(cells: &[(usize, usize)], period: u64) -> Vec<Vec<(usize, usize)>> {
+    let (height, width) = bounding_size(cells);
+    let mut grid = Grid::new(
+        width + 2 * OSCILLATOR_MARGIN,
+        height + 2 * OSCILLATOR_MARGIN,
+    );
+    grid.stamp(cells, OSCILLATOR_MARGIN, OSCILLATOR_MARGIN);
+
+    let rule = Rule::CONWAY;
+    let mut phases = Vec::new();
+    for _ in 0..period {
+        let live: Vec<(usize, usize)> = grid.iter_alive_cells().collect();
+        phases.push(canonicalize(&live));
+
+        let updates: Vec<((usize, usize), CellState)> = grid
+            .iter_cells()
+            .map(|((row, col), state)| {
+                let next = rule.next_state(state, grid.count_neighbors(row, col));
+                ((row, col), next)
+            })
+            .filter(|&((row, col), next)| grid.get(row, col) != Some(next))
+            .collect();
+        for ((row, col), state) in updates {
+            grid.set(row, col, state);
+        }
+    }
+    phases
+}
+
+/// Normalizes cells into a canonical form that is identical for all translations, rotations,
+/// and reflections of the same shape.
+///
+/// Each of the 8 symmetries is translated to the origin and sorted, and the lexicographically
+/// smallest result is chosen.
+fn canonicalize(cells: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    // Each symmetry is a 2×2 matrix `[[a, b], [c, d]]` applied to (row, col)
+    const SYMMETRIES: [[isize; 4]; 8] = [
+        [1, 0, 0, 1],
+        [1, 0, 0, -1],
+        [-1, 0, 0, 1],
+        [-1, 0, 0, -1],
+        [0, 1, 1, 0],
+        [0, 1, -1, 0],
+        [0, -1, 1, 0],
+        [0, -1, -1, 0],
+    ];
+
+    SYMMETRIES
+        .iter()
+        .map(|&[a, b, c, d]| {
+            let transformed: Vec<(isize, isize)> = cells
+                .iter()
+                .map(|&(row, col)| {
+                    let (row, col) = (row as isize, col as isize);
+                    (a * row + b * col, c * row + d * col)
+                })
+                .collect();
+            let min_row = transformed.iter().map(|&(r, _)| r).min().unwrap_or(0);
+            let min_col = transformed.iter().map(|&(_, c)| c).min().unwrap_or(0);
+
+            let mut normalized: Vec<(usize, usize)> = transformed
+                .iter()
+                .map(|&(r, c)| ((r - min_row) as usize, (c - min_col) as usize))
+                .collect();
+            normalized.sort_unstable();
+            normalized
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns each phase of a known oscillator as it appears when run from its listed phase.
+    fn phases_of(name: &str) -> (u64, Vec<Vec<(usize, usize)>>) {
+        let &(_, period, text) = OSCILLATORS.iter().find(|&&(n, ..)| n == name).unwrap();
+        let cells = parse_plaintext(text).unwrap();
+        // Run a bare grid rather than reuse `oscillator_phases`, which canonicalizes
+        let mut grid = Grid::new(40, 40);
+        grid.stamp(&cells, 10, 10);
+        let mut phases = Vec::new();
+        for _ in 0..period {
+            phases.push(grid.iter_alive_cells().collect());
+            let updates: Vec<_> = grid
+                .iter_cells()
+                .map(|((row, col), state)| {
+                    let next = Rule::CONWAY.next_state(state, grid.count_neighbors(row, col));
+                    ((row, col), next)
+                })
+                .collect();
+            for ((row, col), state) in updates {
+                grid.set(row, col, state);
+            }
+        }
+        (period, phases)
+    }
+
+    /// Places cells given as absolute positions on a fresh grid, offset by (`row`, `col`).
+    fn grid_with(cells: &[(usize, usize)], size: usize, row: usize, col: usize) -> Grid {
+        let (top, left) = cells
+            .iter()
+            .fold((usize::MAX, usize::MAX), |(t, l), &(r, c)| {
+                (t.min(r), l.min(c))
+            });
+        let cells: Vec<_> = cells.iter().map(|&(r, c)| (r - top, c - left)).collect();
+        let mut grid = Grid::new(size, size);
+        grid.stamp(&cells, row, col);
+        grid
+    }
+
+    /// Sorts cells for comparison regardless of parse order.
+    fn sorted(mut cells: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+        cells.sort_unstable();
+        cells
+    }
+
+    const GLIDER: [(usize, usize); 5] = [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)];
+
+    #[test]
+    fn parses_rle() {
+        let rle = "#N Glider\n#C comment\nx = 3, y = 3, rule = B3/S23\nbo$2bo$3o!";
+        assert_eq!(sorted(from_rle(rle).unwrap()), GLIDER);
+        // Runs spanning lines, multi-digit counts, blank rows, and anything after `!`
+        let rle = "x = 12, y = 4\n12o$\n2$\n10b2o!\ntrailing notes";
+        let cells = from_rle(rle).unwrap();
+        assert_eq!(cells.len(), 14);
+        assert_eq!(cells[..12], (0..12).map(|col| (0, col)).collect::<Vec<_>>());
+        assert_eq!(cells[12..], [(3, 10), (3, 11)]);
+        // Any other letter is a live cell too
+        assert_eq!(sorted(from_rle("bA$2bC$3z!").unwrap()), GLIDER);
+    }
+
+    #[test]
+    fn rejects_malformed_rle() {
+        assert!(from_rle("x = 3, y = 1\n2o?o!").unwrap_err().contains("'?'"));
+        assert_eq!(
+            from_rle("99999999999999999999999o!").unwrap_err(),
+            "run count is too large"
+        );
+        // Positions that overflow, whether from a run of live cells, dead cells, or rows
+        for overflowing in ["2b18446744073709551615o!", "o18446744073709551615b2b!"] {
+            assert_eq!(
+                from_rle(overflowing).unwrap_err(),
+                "pattern position is too large"
+            );
+        }
+        assert_eq!(
+            from_rle("o$18446744073709551615$o!").unwrap_err(),
+            "pattern position is too large"
+        );
+        // A run too long to allocate, alone or on top of earlier cells
+        for oversized in [
+            "99999999999o!".to_string(),
+            format!("o${DEFAULT_MAX_CELLS}o!"),
+        ] {
+            assert!(from_rle(&oversized).unwrap_err().contains("live cells"));
+        }
+        for empty in ["", "x = 0, y = 0\n!", "#C nothing\n3b$2b!"] {
+            assert_eq!(from_rle(empty).unwrap_err(), "pattern has no live cells");
+        }
+    }
+
+    #[test]
+    fn rle_round_trips() {
+        let rule = Rule::CONWAY;
+        for pattern in LIBRARY {
+            let rle = to_rle(pattern.cells, &rule);
+            assert_eq!(
+                sorted(from_rle(&rle).unwrap()),
+                sorted(pattern.cells.to_vec()),
+                "{}",
+                pattern.name
+            );
+        }
+    }
+
+    #[test]
+    fn parses_plaintext() {
+        let text = "!Name: Glider\n.O.\n..O\nOOO\n";
+        assert_eq!(sorted(parse_plaintext(text).unwrap()), GLIDER);
+        assert_eq!(sorted(parse_plaintext(".*.\n..*\n***").unwrap()), GLIDER);
+    }
+
+    #[test]
+    fn parses_ragged_plaintext_rows() {
+        // Trailing dead cells are often left off, and blank lines are empty rows
+        let text = ".O\n..O\nOOO\n\nO";
+        assert_eq!(
+            sorted(parse_plaintext(text).unwrap()),
+            [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2), (4, 0)]
+        );
+        assert_eq!(bounding_size(&parse_plaintext(text).unwrap()), (5, 3));
+    }
+
+    #[test]
+    fn rejects_plaintext_without_live_cells() {
+        for empty in ["", "!only a comment", "...\n...", "ooo"] {
+            assert_eq!(
+                parse_plaintext(empty).unwrap_err(),
+                "pattern has no live cells"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_pattern_tells_rle_from_plaintext() {
+        assert_eq!(
+            sorted(parse_pattern("#C a glider\nx = 3, y = 3\nbo$2bo$3o!").unwrap()),
+            GLIDER
+        );
+        assert_eq!(
+            sorted(parse_pattern("!glider\n.O.\n..O\nOOO").unwrap()),
+            GLIDER
+        );
+        // Without a header, RLE reads as plaintext with no live cells
+        assert!(parse_pattern("bo$2bo$3o!").is_err());
+    }
+
+    #[test]
+    fn r180_turns_a_glider_to_fly_the_other_way() {
+        let transform: Transform = "r180".parse().unwrap();
+        assert_eq!(transform, Transform::rotation(2));
+        let turned = sorted(transform.apply(&GLIDER));
+        // .O.      OOO
+        // ..O  ->  O..
+        // OOO      .O.
+        assert_eq!(turned, [(0, 0), (0, 1), (0, 2), (1, 0), (2, 1)]);
+        // Half a turn is both flips
+        assert_eq!(
+            sorted("fh+fv".parse::<Transform>().unwrap().apply(&GLIDER)),
+            turned
+        );
+
+        // The glider heads down and right, so the turned one heads up and left
+        let mut game = crate::game::Game::new((20, 20));
+        game.grid.stamp(&turned, 10, 10);
+        for _ in 0..4 {
+            game.step();
+        }
+        let moved: Vec<_> = turned
+            .iter()
+            .map(|&(row, col)| (row + 9, col + 9))
+            .collect();
+        assert_eq!(sorted(game.grid.iter_alive_cells().collect()), moved);
+    }
+
+    #[test]
+    fn identify_oscillator_names_every_phase_in_any_orientation() {
+        for &(name, ..) in OSCILLATORS {
+            let (period, phases) = phases_of(name);
+            for (generation, phase) in phases.iter().enumerate() {
+                for quarter_turns in 0..4 {
+                    let turned = rotate(phase, quarter_turns);
+                    let grid = grid_with(&turned, 40, 5, 7);
+                    assert_eq!(
+                        identify_oscillator(&grid, period),
+                        Some(name),
+                        "{name} phase {generation} turned {quarter_turns}"
+                    );
+                    assert_eq!(identify_oscillator(&grid, period + 1), None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn identify_oscillator_ignores_debris_elsewhere() {
+        let (period, phases) = phases_of("pulsar");
+        let mut grid = grid_with(&phases[1], 40, 2, 2);
+        grid.stamp(find("block").unwrap().cells, 30, 30);
+        grid.stamp(find("beehive").unwrap().cells, 2, 30);
+        assert_eq!(identify_oscillator(&grid, period), Some("pulsar"));
+
+        let mut grid = grid_with(&[(0, 0), (0, 1), (0, 2)], 20, 10, 4);
+        grid.stamp(find("boat").unwrap().cells, 2, 2);
+        assert_eq!(identify_oscillator(&grid, 2), Some("blinker"));
+    }
+
+    #[test]
+    fn identify_oscillator_ignores_still_parts_touching_it() {
+        // A block right next to a blinker makes a different object
+        let mut grid = Grid::new(20, 20);
+        grid.stamp(&[(0, 0), (0, 1), (0, 2)], 5, 5);
+        grid.stamp(find("block").unwrap().cells, 4, 9);
+        assert_eq!(identify_oscillator(&grid, 2), None);
+    }
+
+    #[test]
+    fn identify_oscillator_follows_wrapped_edges() {
+        // A vertical blinker split across the top and bottom edges
+        let mut grid = Grid::new(10, 10);
+        for row in [9, 0, 1] {
+            grid.set(row, 4, CellState::Alive);
+        }
+        assert_eq!(identify_oscillator(&grid, 2), Some("blinker"));
+
+        // A pentadecathlon across the corner, in a phase where it has split in two
+        let (period, phases) = phases_of("pentadecathlon");
+        let mut grid = Grid::new(30, 30);
+        for &(row, col) in &phases[5] {
+            grid.set_wrapped(row as isize - 12, col as isize + 19, CellState::Alive);
+        }
+        let cells: Vec<_> = grid.iter_alive_cells().collect();
+        assert!(cells.iter().any(|&(row, _)| row < 5) && cells.iter().any(|&(row, _)| row > 25));
+        assert!(cells.iter().any(|&(_, col)| col < 5) && cells.iter().any(|&(_, col)| col > 25));
+        assert_eq!(identify_oscillator(&grid, period), Some("pentadecathlon"));
+    }
+
+    #[test]
+    fn identify_oscillator_needs_a_known_period() {
+        let grid = grid_with(&[(0, 0), (0, 1), (0, 2)], 10, 3, 3);
+        assert_eq!(identify_oscillator(&grid, 1), None);
+        assert_eq!(identify_oscillator(&grid, 4), None);
+        assert_eq!(identify_oscillator(&Grid::new(10, 10), 2), None);
+    }
+}
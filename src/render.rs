@@ -0,0 +1,273 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+};
+
+use crate::grid::{BRAILLE_BASE, CellState, Grid};
+
+/// Width and height in cells of the block summarized by each character in [`ShadedRenderer`].
+const SHADE_BLOCK_WIDTH: usize = 4;
+const SHADE_BLOCK_HEIGHT: usize = 8;
+
+/// Character a cell that just died flashes as.
+const SPARKLE_DEATH: char = '░';
+
+/// Range of the 256-color grayscale ramp used for fading trails.
+const TRAIL_DARKEST: u8 = 233;
+const TRAIL_BRIGHTEST: u8 = 243;
+
+/// Colors and cell history a [`Renderer`] draws with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Color of live cells.
+    pub live: Color,
+    /// Color of walls.
+    pub wall: Color,
+    /// Color behind the cells, or [`Color::Reset`] to leave the terminal's background showing.
+    pub background: Color,
+    /// Number of generations dead cells stay visible, or `None` to disable trails.
+    pub trail_length: Option<u16>,
+    /// Color cells born or died in the latest generation flash in, or `None` to draw them
+    /// normally.
+    pub sparkle: Option<Color>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            live: Color::White,
+            wall: Color::Blue,
+            background: Color::Reset,
+            trail_length: None,
+            sparkle: None,
+        }
+    }
+}
+
+/// A way of drawing grid cells as terminal characters.
+///
+/// Besides drawing, a renderer maps between cells and the characters drawing them, which the
+/// ruler, selection highlight, and mouse handling rely on.
+pub trait Renderer {
+    /// Converts a cell's (row, col) into a character (x, y) offset from the grid's origin.
+    fn char_offset(&self, row: usize, col: usize) -> (usize, usize);
+
+    /// Converts a character (x, y) offset from the grid's origin into the (row, col) of the
+    /// top-left cell drawn by that character.
+    fn cell_at(&self, x: usize, y: usize) -> (usize, usize);
+
+    /// Returns the (width, height) in characters needed to display a grid.
+    fn display_size(&self, grid_width: usize, grid_height: usize) -> (usize, usize);
+
+    /// Returns the (width, height) in cells that fit within an area of characters.
+    fn capacity(&self, area_width: usize, area_height: usize) -> (usize, usize);
+
+    /// Returns the number of characters across each cell, or `1` if cells share characters.
+    fn cell_chars(&self) -> usize {
+        1
+    }
+
+    /// Draws the grid's visible characters into the buffer at `area`, which must be at least
+    /// [`Renderer::display_size`] big.
+    ///
+    /// The buffer starts out blank every frame and ratatui only sends changed cells on to the
+    /// terminal, so blank characters are skipped entirely. The background, if any, is filled in
+    /// first so those skipped characters still pick it up.
+    fn render(&self, grid: &Grid, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        if theme.background != Color::Reset {
+            buf.set_style(area, Style::default().bg(theme.background));
+        }
+        let (width, height) = self.display_size(grid.width(), grid.height());
+        let live_style = Style::default().fg(theme.live);
+        for y in 0..height {
+            for x in 0..width {
+                let glyph = self.glyph(grid, x, y);
+                if glyph == ' ' {
+                    continue;
+                }
+                if let Some(cell) = buf.cell_mut((area.x + x as u16, area.y + y as u16)) {
+                    cell.set_char(glyph).set_style(live_style);
+                }
+            }
+        }
+    }
+
+    /// Returns the character drawn at (x, y), or `' '` to leave it blank.
+    fn glyph(&self, grid: &Grid, x: usize, y: usize) -> char;
+}
+
+/// One cell per `cell_width`-character `██` block, the only renderer that shows walls, trails,
+/// and sparkles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockRenderer {
+    /// Characters per cell.
+    pub cell_width: usize,
+}
+
+impl Renderer for BlockRenderer {
+    fn char_offset(&self, row: usize, col: usize) -> (usize, usize) {
+        (col * self.cell_width, row)
+    }
+
+    fn cell_at(&self, x: usize, y: usize) -> (usize, usize) {
+        (y, x / self.cell_width)
+    }
+
+    fn display_size(&self, grid_width: usize, grid_height: usize) -> (usize, usize) {
+        (grid_width * self.cell_width, grid_height)
+    }
+
+    fn capacity(&self, area_width: usize, area_height: usize) -> (usize, usize) {
+        (area_width / self.cell_width, area_height)
+    }
+
+    fn cell_chars(&self) -> usize {
+        self.cell_width
+    }
+
+    fn render(&self, grid: &Grid, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        if theme.background != Color::Reset {
+            buf.set_style(area, Style::default().bg(theme.background));
+        }
+        let live_style = Style::default().fg(theme.live);
+        let wall_style = Style::default().fg(theme.wall);
+        for ((row, col), state) in grid.iter_cells() {
+            let sparkle = theme.sparkle.filter(|_| grid.changed_last_step(row, col));
+            let mut glyph = '█';
+            let style = match (state, sparkle) {
+                _ if grid.is_wall(row, col) => wall_style,
+                // Births flash as solid blocks and deaths as faint ones
+                (CellState::Alive, Some(color)) => Style::default().fg(color),
+                (CellState::Dead, Some(color)) => {
+                    glyph = SPARKLE_DEATH;
+                    Style::default().fg(color)
+                }
+                (CellState::Alive, None) => live_style,
+                (CellState::Dead, None) => {
+                    let since_death = grid.since_death(row, col);
+                    match theme
+                        .trail_length
+                        .and_then(|trail_length| trail_color(since_death, trail_length))
+                    {
+                        Some(color) => Style::default().fg(color),
+                        None => continue,
+                    }
+                }
+            };
+            for dx in 0..self.cell_width {
+                let x = area.x + (col * self.cell_width + dx) as u16;
+                if let Some(cell) = buf.cell_mut((x, area.y + row as u16)) {
+                    cell.set_char(glyph).set_style(style);
+                }
+            }
+        }
+    }
+
+    fn glyph(&self, grid: &Grid, x: usize, y: usize) -> char {
+        match grid.get(y, x / self.cell_width) {
+            Some(CellState::Alive) => '█',
+            _ => ' ',
+        }
+    }
+}
+
+/// Two vertically stacked cells per half-block character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HalfBlockRenderer;
+
+impl Renderer for HalfBlockRenderer {
+    fn char_offset(&self, row: usize, col: usize) -> (usize, usize) {
+        (col, row / 2)
+    }
+
+    fn cell_at(&self, x: usize, y: usize) -> (usize, usize) {
+        (y * 2, x)
+    }
+
+    fn display_size(&self, grid_width: usize, grid_height: usize) -> (usize, usize) {
+        (grid_width, grid_height.div_ceil(2))
+    }
+
+    fn capacity(&self, area_width: usize, area_height: usize) -> (usize, usize) {
+        (area_width, area_height * 2)
+    }
+
+    fn glyph(&self, grid: &Grid, x: usize, y: usize) -> char {
+        grid.halfblock_char(y, x)
+    }
+}
+
+/// 2×4 cells per Braille character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BrailleRenderer;
+
+impl Renderer for BrailleRenderer {
+    fn char_offset(&self, row: usize, col: usize) -> (usize, usize) {
+        (col / 2, row / 4)
+    }
+
+    fn cell_at(&self, x: usize, y: usize) -> (usize, usize) {
+        (y * 4, x * 2)
+    }
+
+    fn display_size(&self, grid_width: usize, grid_height: usize) -> (usize, usize) {
+        (grid_width.div_ceil(2), grid_height.div_ceil(4))
+    }
+
+    fn capacity(&self, area_width: usize, area_height: usize) -> (usize, usize) {
+        (area_width * 2, area_height * 4)
+    }
+
+    fn glyph(&self, grid: &Grid, x: usize, y: usize) -> char {
+        match grid.braille_char(y, x) {
+            glyph if u32::from(glyph) == BRAILLE_BASE => ' ',
+            glyph => glyph,
+        }
+    }
+}
+
+/// Blocks of cells per shade character, darker the more of them are alive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShadedRenderer;
+
+impl Renderer for ShadedRenderer {
+    fn char_offset(&self, row: usize, col: usize) -> (usize, usize) {
+        (col / SHADE_BLOCK_WIDTH, row / SHADE_BLOCK_HEIGHT)
+    }
+
+    fn cell_at(&self, x: usize, y: usize) -> (usize, usize) {
+        (y * SHADE_BLOCK_HEIGHT, x * SHADE_BLOCK_WIDTH)
+    }
+
+    fn display_size(&self, grid_width: usize, grid_height: usize) -> (usize, usize) {
+        (
+            grid_width.div_ceil(SHADE_BLOCK_WIDTH),
+            grid_height.div_ceil(SHADE_BLOCK_HEIGHT),
+        )
+    }
+
+    fn capacity(&self, area_width: usize, area_height: usize) -> (usize, usize) {
+        (
+            area_width * SHADE_BLOCK_WIDTH,
+            area_height * SHADE_BLOCK_HEIGHT,
+        )
+    }
+
+    fn glyph(&self, grid: &Grid, x: usize, y: usize) -> char {
+        grid.shaded_char(y, x, SHADE_BLOCK_WIDTH, SHADE_BLOCK_HEIGHT)
+    }
+}
+
+/// Picks the color of a dead cell's trail, or `None` if it has faded out completely.
+///
+/// Trails start at a dim gray and darken linearly until they disappear.
+fn trail_color(since_death: u16, trail_length: u16) -> Option<Color> {
+    if since_death == 0 || since_death > trail_length {
+        return None;
+    }
+    let remaining = (trail_length - since_death + 1) as f32 / trail_length as f32;
+    let range = (TRAIL_BRIGHTEST - TRAIL_DARKEST) as f32;
+    let shade = TRAIL_DARKEST + (remaining * range).round() as u8;
+    Some(Color::Indexed(shade))
+}
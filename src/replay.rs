@@ -0,0 +1,171 @@
+use crate::{
+    game::{Game, GameSnapshot},
+    rule::Rule,
+};
+
+/// Generations between the snapshots a [`Recording`] keeps for seeking.
+const KEYFRAME_INTERVAL: u64 = 100;
+
+/// Longest [`Recording`] in generations.
+pub const MAX_RECORDING_LENGTH: u64 = 10_000;
+
+/// A recorded run that can be replayed and seeked through.
+///
+/// Life is deterministic, so a recording only keeps its starting board plus a snapshot every
+/// [`KEYFRAME_INTERVAL`] generations to seek from, re-running the generations in between. That
+/// only holds while the run is left alone, so [`Recording::follows`] checks nothing changed it
+/// between steps.
+#[derive(Debug, Clone)]
+pub struct Recording {
+    /// Rule the run was recorded under.
+    rule: Rule,
+    /// Board at the start and every [`KEYFRAME_INTERVAL`] generations after.
+    keyframes: Vec<GameSnapshot>,
+    /// Board as of the last recorded generation.
+    last: GameSnapshot,
+    /// Number of generations recorded.
+    length: u64,
+}
+
+impl Recording {
+    /// Starts a recording from the game's current board.
+    pub fn new(game: &Game) -> Self {
+        let start = game.snapshot();
+        Self {
+            rule: game.rule,
+            keyframes: vec![start.clone()],
+            last: start,
+            length: 0,
+        }
+    }
+
+    /// Returns true if the game is still where the recording left it, so stepping it continues
+    /// the recorded run.
+    ///
+    /// Edits, jumps, and changes to the rule, edges, or stepping mode since the last recorded
+    /// generation all break this, since replaying the recording wouldn't reproduce them.
+    pub fn follows(&self, game: &Game) -> bool {
+        let (last, grid) = (self.last.grid(), &game.grid);
+        game.generation == self.last.generation()
+            && game.rule == self.rule
+            && !game.is_reversible()
+            && grid == last
+            && grid.wrap_x() == last.wrap_x()
+            && grid.wrap_y() == last.wrap_y()
+            && grid.walls_alive() == last.walls_alive()
+    }
+
+    /// Accounts for one step of the game being recorded.
+    ///
+    /// Returns `false`, recording nothing, once [`MAX_RECORDING_LENGTH`] generations have been
+    /// recorded.
+    pub fn record(&mut self, game: &Game) -> bool {
+        if self.length >= MAX_RECORDING_LENGTH {
+            return false;
+        }
+        self.length += 1;
+        self.last = game.snapshot();
+        if self.length.is_multiple_of(KEYFRAME_INTERVAL) {
+            self.keyframes.push(self.last.clone());
+        }
+        true
+    }
+
+    /// Returns the number of generations recorded.
+    pub fn len(&self) -> u64 {
+        self.length
+    }
+
+    /// Returns true if no generations have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the generation the recording started at.
+    pub fn start_generation(&self) -> u64 {
+        self.keyframes[0].generation()
+    }
+
+    /// Puts the game's board and rule where they were `position` generations into the
+    /// recording, clamped to its length.
+    pub fn seek(&self, game: &mut Game, position: u64) {
+        let position = position.min(self.length);
+        let keyframe = (position / KEYFRAME_INTERVAL) as usize;
+        game.restore(&self.keyframes[keyframe]);
+        game.rule = self.rule;
+        for _ in 0..position % KEYFRAME_INTERVAL {
+            game.step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::grid::CellState;
+
+    /// Returns a random soup, seeded so the run is the same every time.
+    fn soup() -> Game {
+        let mut game = Game::with_rng((32, 24), StdRng::seed_from_u64(395));
+        game.randomize(0.35);
+        game
+    }
+
+    #[test]
+    fn replay_matches_the_live_run() {
+        let mut game = soup();
+        let mut recording = Recording::new(&game);
+        let mut boards = vec![game.grid.clone()];
+        for _ in 0..(2 * KEYFRAME_INTERVAL + 30) {
+            assert!(recording.follows(&game));
+            game.step();
+            assert!(recording.record(&game));
+            boards.push(game.grid.clone());
+        }
+
+        let mut replay = soup();
+        replay.rule = Rule::parse("B36/S23").unwrap();
+        // Seek out of order, across keyframes and past the end
+        for position in [230, 0, 1, 99, 100, 101, 199, 200, 57, 231, 1000] {
+            recording.seek(&mut replay, position);
+            let position = position.min(recording.len());
+            assert!(
+                replay.grid == boards[position as usize],
+                "position {position}"
+            );
+            assert_eq!(replay.generation, recording.start_generation() + position);
+            assert_eq!(replay.rule, Rule::CONWAY);
+        }
+    }
+
+    #[test]
+    fn edits_and_jumps_break_the_recording() {
+        let mut game = soup();
+        let mut recording = Recording::new(&game);
+        game.step();
+        recording.record(&game);
+        assert!(recording.follows(&game));
+
+        let edits: [fn(&mut Game); 6] = [
+            |game| {
+                let state = game.grid.get(3, 3).unwrap();
+                game.grid
+                    .set(3, 3, CellState::from_alive(!state.is_alive()));
+            },
+            |game| game.step(),
+            |game| game.rule = Rule::parse("B36/S23").unwrap(),
+            |game| game.grid.set_wrap(!game.grid.wrap_x(), game.grid.wrap_y()),
+            |game| {
+                game.grid.set_wall(0, 0, true);
+            },
+            |game| game.grid.set_walls_alive(!game.grid.walls_alive()),
+        ];
+        for (idx, edit) in edits.iter().enumerate() {
+            let mut edited = game.clone();
+            edit(&mut edited);
+            assert!(!recording.follows(&edited), "edit {idx}");
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use std::{error::Error, fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::grid::CellState;
+
+/// Birth and survival conditions for a Life-like cellular automaton.
+///
+/// Conditions are stored as bitmasks where bit `n` is set if `n` live neighbors satisfy it.
+/// Rules normally count the 8 cells around a cell (outer totalistic), but can count the cell
+/// itself too (totalistic), for counts from 0 to 9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// Neighbor counts that bring a dead cell to life.
+    birth: u16,
+    /// Neighbor counts that keep a live cell alive.
+    survival: u16,
+    /// Whether the cell itself counts along with its neighbors.
+    count_self: bool,
+}
+
+impl Rule {
+    /// Conway's Game of Life (B3/S23).
+    pub const CONWAY: Rule = Rule {
+        birth: 1 << 3,
+        survival: (1 << 2) | (1 << 3),
+        count_self: false,
+    };
+
+    /// Creates a rule from lists of birth and survival neighbor counts.
+    pub fn new(birth: &[u8], survival: &[u8]) -> Self {
+        assert!(
+            birth.iter().chain(survival).all(|&n| n <= 8),
+            "Neighbor counts must be within 0 to 8"
+        );
+
+        Self::from_counts(birth, survival, false)
+    }
+
+    /// Creates a rule that counts the cell itself along with its neighbors, from lists of
+    /// birth and survival counts.
+    pub fn new_counting_self(birth: &[u8], survival: &[u8]) -> Self {
+        assert!(
+            birth.iter().chain(survival).all(|&n| n <= 9),
+            "Counts including the cell itself must be within 0 to 9"
+        );
+        Self::from_counts(birth, survival, true)
+    }
+
+    /// Creates a rule from lists of counts already checked to be in range.
+    fn from_counts(birth: &[u8], survival: &[u8], count_self: bool) -> Self {
+        let mask = |counts: &[u8]| counts.iter().fold(0, |mask, &n| mask | (1 << n));
+        Self {
+            birth: mask(birth),
+            survival: mask(survival),
+            count_self,
+        }
+    }
+
+    /// Returns true if the rule counts the cell itself along with its neighbors.
+    pub fn counts_self(&self) -> bool {
+        self.count_self
+    }
+
+    /// Parses a rule in B/S notation, e.g. `B3/S23` for Conway's Game of Life.
+    ///
+    /// Letters are case-insensitive and either list of digits may be empty (e.g. `B2/S`). Each
+    /// digit must be a neighbor count from 0 to 8 and may appear at most once per list. A
+    /// trailing `/T` (e.g. `B3/S34/T`) makes the rule count the cell itself too, allowing
+    /// counts up to 9.
+    pub fn parse(input: &str) -> Result<Self, ParseRuleError> {
+        let mut parts = input.trim().split('/');
+        let (Some(birth), Some(survival)) = (parts.next(), parts.next()) else {
+            return Err(ParseRuleError::Format);
+        };
+        let count_self = match parts.next() {
+            None => false,
+            Some(part) if part.eq_ignore_ascii_case("T") => true,
+            Some(_) => return Err(ParseRuleError::Format),
+        };
+        if parts.next().is_some() {
+            return Err(ParseRuleError::Format);
+        }
+        let max_count = if count_self { 9 } else { 8 };
+
+        let digits = |part: &str, prefix: char| -> Result<u16, ParseRuleError> {
+            let mut chars = part.chars();
+            if !chars
+                .next()
+                .is_some_and(|c| c.eq_ignore_ascii_case(&prefix))
+            {
+                return Err(ParseRuleError::Format);
+            }
+            chars.try_fold(0u16, |mask, c| {
+                let n = c
+                    .to_digit(10)
+                    .filter(|&n| n <= max_count)
+                    .ok_or(ParseRuleError::InvalidDigit(c))?;
+                if mask & (1 << n) != 0 {
+                    return Err(ParseRuleError::DuplicateDigit(c));
+                }
+                Ok(mask | (1 << n))
+            })
+        };
+
+        Ok(Self {
+            birth: digits(birth, 'B')?,
+            survival: digits(survival, 'S')?,
+            count_self,
+        })
+    }
+
+    /// Computes the next state of a cell given its current state and live neighbor count.
+    ///
+    /// For a rule that [counts the cell itself](Rule::counts_self), `neighbors` must include
+    /// the cell.
+    pub fn next_state(&self, state: CellState, neighbors: u8) -> CellState {
+        let mask = match state {
+            CellState::Alive => self.survival,
+            CellState::Dead => self.birth,
+        };
+        if mask & (1 << neighbors) != 0 {
+            CellState::Alive
+        } else {
+            CellState::Dead
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self::CONWAY
+    }
+}
+
+impl fmt::Display for Rule {
+    /// Formats the rule in B/S notation (e.g. `B3/S23`, or `B3/S34/T` counting the cell itself).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = |mask: u16| -> String {
+            (0..=9)
+                .filter(|n| mask & (1 << n) != 0)
+                .map(|n| char::from(b'0' + n))
+                .collect()
+        };
+        write!(f, "B{}/S{}", digits(self.birth), digits(self.survival))?;
+        if self.count_self {
+            write!(f, "/T")?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializes the rule as a string in B/S notation.
+#[cfg(feature = "serde")]
+impl Serialize for Rule {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Rule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let notation = String::deserialize(deserializer)?;
+        Rule::parse(&notation).map_err(de::Error::custom)
+    }
+}
+
+/// Error returned when parsing a [`Rule`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRuleError {
+    /// The input isn't of the form `B<digits>/S<digits>`, optionally followed by `/T`.
+    Format,
+    /// A character that isn't a neighbor count from 0 to 8 (or 9 when counting the cell itself).
+    InvalidDigit(char),
+    /// A neighbor count listed more than once.
+    DuplicateDigit(char),
+}
+
+impl fmt::Display for ParseRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRuleError::Format => write!(f, "expected a rule like B3/S23"),
+            ParseRuleError::InvalidDigit(c) => write!(f, "invalid neighbor count '{c}'"),
+            ParseRuleError::DuplicateDigit(c) => write!(f, "duplicate neighbor count '{c}'"),
+        }
+    }
+}
+
+impl Error for ParseRuleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_displays_canonical_rules() {
+        for notation in ["B3/S23", "B36/S23", "B2/S", "B/S", "B3/S34/T", "B9/S09/T"] {
+            assert_eq!(Rule::parse(notation).unwrap().to_string(), notation);
+        }
+        assert_eq!(Rule::parse("B3/S23").unwrap(), Rule::CONWAY);
+        assert_eq!(Rule::parse("B36/S23").unwrap(), Rule::new(&[3, 6], &[2, 3]));
+        // Letters are case-insensitive, surrounding whitespace is ignored, and digits may be
+        // listed in any order
+        assert_eq!(Rule::parse(" b3/s32 ").unwrap(), Rule::CONWAY);
+        let counting_self = Rule::parse("b3/s34/t").unwrap();
+        assert!(counting_self.counts_self());
+        assert_eq!(counting_self.to_string(), "B3/S34/T");
+    }
+
+    #[test]
+    fn rejects_malformed_rules() {
+        let cases = [
+            ("", ParseRuleError::Format),
+            ("B3", ParseRuleError::Format),
+            ("B3/23", ParseRuleError::Format),
+            ("S23/B3", ParseRuleError::Format),
+            ("B3/S23/x", ParseRuleError::Format),
+            ("B3/S23/T/T", ParseRuleError::Format),
+            ("B3x/S23", ParseRuleError::InvalidDigit('x')),
+            ("B9/S", ParseRuleError::InvalidDigit('9')),
+            ("B3/S239", ParseRuleError::InvalidDigit('9')),
+            ("B33/S23", ParseRuleError::DuplicateDigit('3')),
+            ("B3/S232", ParseRuleError::DuplicateDigit('2')),
+        ];
+        for (input, error) in cases {
+            assert_eq!(Rule::parse(input), Err(error), "parsing {input:?}");
+        }
+    }
+}
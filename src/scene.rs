@@ -0,0 +1,171 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::patterns::{self, Transform};
+
+/// A set of patterns arranged on the board.
+///
+/// Scenes are written one entry per line as `pattern, row, col, rotation`, where `pattern` is
+/// the name of a built-in pattern or a path to a plaintext (`.cells`) file, and `rotation` is
+/// `0`, `90`, `180`, or `270` degrees clockwise or a [`Transform`] such as `fh+r90`. Blank lines
+/// and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Scene {
+    /// Patterns to place, in order.
+    pub entries: Vec<SceneEntry>,
+}
+
+/// A single pattern placement within a [`Scene`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SceneEntry {
+    /// Line number in the scene file, for error reporting.
+    pub line: usize,
+    /// Built-in pattern name or path to a pattern file.
+    pub source: String,
+    /// Row of the pattern's top-left corner.
+    pub row: usize,
+    /// Column of the pattern's top-left corner.
+    pub col: usize,
+    /// Rotation and reflection to apply.
+    pub transform: Transform,
+}
+
+impl Scene {
+    /// Reads and parses a scene file.
+    ///
+    /// Relative pattern paths are resolved against the scene file's directory.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        let mut scene = Self::parse(&text)?;
+
+        let base = path.parent().unwrap_or(Path::new(""));
+        for entry in &mut scene.entries {
+            if patterns::find(&entry.source).is_none() && Path::new(&entry.source).is_relative() {
+                entry.source = base.join(&entry.source).to_string_lossy().into_owned();
+            }
+        }
+        Ok(scene)
+    }
+
+    /// Parses scene text.
+    ///
+    /// Fails on the first malformed line, since a syntax error usually means the whole file is
+    /// wrong.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let entries = text
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (idx + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(line, content)| {
+                SceneEntry::parse(line, content).map_err(|e| format!("line {line}: {e}"))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { entries })
+    }
+}
+
+impl SceneEntry {
+    /// Parses a `pattern, row, col, rotation` line.
+    fn parse(line: usize, content: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = content.split(',').map(str::trim).collect();
+        let [source, row, col, rotation] = fields.as_slice() else {
+            return Err("expected `pattern, row, col, rotation`".to_string());
+        };
+
+        let parse_usize = |field: &str| {
+            field
+                .parse::<usize>()
+                .map_err(|_| format!("invalid number: {field}"))
+        };
+        let transform = match *rotation {
+            "0" => Transform::rotation(0),
+            "90" => Transform::rotation(1),
+            "180" => Transform::rotation(2),
+            "270" => Transform::rotation(3),
+            _ => rotation
+                .parse()
+                .map_err(|_| format!("invalid rotation: {rotation}"))?,
+        };
+
+        Ok(Self {
+            line,
+            source: source.to_string(),
+            row: parse_usize(row)?,
+            col: parse_usize(col)?,
+            transform,
+        })
+    }
+
+    /// Resolves the entry's pattern and applies its transform.
+    ///
+    /// Built-in pattern names take precedence over file paths.
+    pub fn cells(&self) -> Result<Vec<(usize, usize)>, String> {
+        let cells = match patterns::find(&self.source) {
+            Some(pattern) => pattern.cells.to_vec(),
+            None => {
+                let path = PathBuf::from(&self.source);
+                if !path.exists() {
+                    return Err(format!("unknown pattern: {}", self.source));
+                }
+                let text = fs::read_to_string(&path)
+                    .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+                patterns::parse_plaintext(&text)?
+            }
+        };
+        Ok(self.transform.apply(&cells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_entries_skipping_blanks_and_comments() {
+        let scene =
+            Scene::parse("# a scene\n\nglider, 1, 2, 90\n  block , 3, 4, fh+r90  \n").unwrap();
+        assert_eq!(
+            scene.entries,
+            [
+                SceneEntry {
+                    line: 3,
+                    source: "glider".to_string(),
+                    row: 1,
+                    col: 2,
+                    transform: Transform::rotation(1),
+                },
+                SceneEntry {
+                    line: 4,
+                    source: "block".to_string(),
+                    row: 3,
+                    col: 4,
+                    transform: "fh+r90".parse().unwrap(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        let cases = [
+            (
+                "glider, 1, 2",
+                "line 1: expected `pattern, row, col, rotation`",
+            ),
+            (
+                "glider, 1, 2, 0, 0",
+                "line 1: expected `pattern, row, col, rotation`",
+            ),
+            ("glider, -1, 2, 0", "line 1: invalid number: -1"),
+            ("\nglider, 1, x, 0", "line 2: invalid number: x"),
+            ("glider, 1, 2, 45", "line 1: invalid rotation: 45"),
+        ];
+        for (text, error) in cases {
+            assert_eq!(Scene::parse(text).unwrap_err(), error, "parsing {text:?}");
+        }
+    }
+}
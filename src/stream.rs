@@ -0,0 +1,149 @@
+use std::{
+    io::{self, ErrorKind, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    thread,
+};
+
+use crate::{game::Game, patterns};
+
+/// Encodes the game's current generation as one frame of the stream.
+///
+/// Frames are RLE with the live cells at their positions on the grid, preceded by
+/// `#C generation = <n>` and `#C grid = <width>x<height>` comments. Each ends with RLE's `!`
+/// terminator and a newline, so clients can split the stream without knowing the grid size.
+pub fn encode_frame(game: &Game) -> String {
+    let cells: Vec<_> = game.grid.iter_alive_cells().collect();
+    let (width, height) = game.grid.dimensions();
+    format!(
+        "#C generation = {}\n#C grid = {width}x{height}\n{}",
+        game.generation,
+        patterns::to_rle(&cells, &game.rule)
+    )
+}
+
+/// Steps the game at its tick interval without the TUI, writing every generation to stdout.
+///
+/// Writes block, so a slow reader slows the simulation down rather than piling up frames.
+/// Returns once stdout is closed (e.g. the reader of a pipe exits), or on any other write error.
+pub fn run_stdout(game: &mut Game) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    loop {
+        match stdout
+            .write_all(encode_frame(game).as_bytes())
+            .and_then(|()| stdout.flush())
+        {
+            Err(e) if e.kind() == ErrorKind::BrokenPipe => return Ok(()),
+            result => result?,
+        }
+        thread::sleep(game.tick_interval);
+        game.step();
+    }
+}
+
+/// A TCP server sending each generation to every connected client.
+///
+/// The server never blocks the simulation: clients are accepted and written to without
+/// waiting, a client that hasn't taken the previous frame yet skips generations until it has,
+/// and a client that disconnects or fails is dropped.
+#[derive(Debug)]
+pub struct StreamServer {
+    /// Socket accepting new clients.
+    listener: TcpListener,
+    /// Connected clients.
+    clients: Vec<Client>,
+}
+
+/// A connected client and the frame it is part way through receiving.
+#[derive(Debug)]
+struct Client {
+    /// Connection to the client.
+    stream: TcpStream,
+    /// Frame being sent, empty once it has all been written.
+    pending: Vec<u8>,
+    /// Number of bytes of `pending` already written.
+    written: usize,
+}
+
+impl StreamServer {
+    /// Listens for clients on `addr`.
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(Self {
+            listener,
+            clients: Vec::new(),
+        })
+    }
+
+    /// Returns the address the server is listening on.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Returns the number of connected clients.
+    pub fn client_count(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Accepts any waiting clients, then sends the game's current generation to each client
+    /// that is ready for it.
+    pub fn send(&mut self, game: &Game) {
+        self.accept();
+        if self.clients.is_empty() {
+            return;
+        }
+
+        let frame = encode_frame(game).into_bytes();
+        self.clients.retain_mut(|client| {
+            if client.flush().is_err() {
+                return false;
+            }
+            if client.pending.is_empty() {
+                client.pending.clone_from(&frame);
+                client.written = 0;
+            }
+            client.flush().is_ok()
+        });
+    }
+
+    /// Accepts every client waiting to connect.
+    fn accept(&mut self) {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    // A client that can't be made non-blocking would stall the simulation
+                    if stream.set_nonblocking(true).is_ok() {
+                        self.clients.push(Client {
+                            stream,
+                            pending: Vec::new(),
+                            written: 0,
+                        });
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                // `WouldBlock` once no more clients are waiting; other errors are retried on
+                // the next frame
+                Err(_) => return,
+            }
+        }
+    }
+}
+
+impl Client {
+    /// Writes as much of the pending frame as the connection takes without blocking.
+    ///
+    /// Fails if the client disconnected or the connection broke.
+    fn flush(&mut self) -> io::Result<()> {
+        while self.written < self.pending.len() {
+            match self.stream.write(&self.pending[self.written..]) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(n) => self.written += n,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        self.pending.clear();
+        Ok(())
+    }
+}
@@ -1,23 +1,106 @@
+use std::{cell::Cell, time::Duration};
+
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
-    text::Line,
-    widgets::{Block, Borders, Paragraph, Widget},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Gauge, Paragraph, Widget},
 };
 
-use crate::{app::App, game::Game};
-use crate::{game::GameState, grid::Grid};
+use crate::{
+    app::{App, Jump, KeyHint, Replay, StatusField, key_bindings, key_hints},
+    game::Game,
+};
+use crate::{
+    game::GameState,
+    grid::{Grid, GridBackend},
+    patterns,
+    render::{BlockRenderer, BrailleRenderer, HalfBlockRenderer, Renderer, ShadedRenderer, Theme},
+    rule::Rule,
+};
 
 /// Grid dimension bounds.
 const MIN_GRID_WIDTH: usize = 20;
 const MIN_GRID_HEIGHT: usize = 15;
-const MAX_GRID_WIDTH: usize = 200;
-const MAX_GRID_HEIGHT: usize = 100;
+pub const MAX_GRID_WIDTH: usize = 200;
+pub const MAX_GRID_HEIGHT: usize = 100;
 
-/// Width of each cell in terminal characters.
+/// Default width of each cell in terminal characters.
 /// Uses 2 characters per cell for better visual proportions.
-const CELL_WIDTH: usize = 2;
+pub const DEFAULT_CELL_WIDTH: usize = 2;
+/// Widest selectable cell width in terminal characters.
+pub const MAX_CELL_WIDTH: usize = 3;
+
+/// Grid panel title when no pattern name is known.
+const DEFAULT_TITLE: &str = "Game of Life";
+
+/// Number of cells between ruler labels.
+const RULER_SPACING: usize = 10;
+
+/// Bar glyphs for histograms, from empty to full.
+const BARS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// How grid cells are packed into terminal characters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RenderMode {
+    /// One cell per two-character `██` block.
+    #[default]
+    Block,
+    /// Two vertically stacked cells per half-block character.
+    HalfBlock,
+    /// 2×4 cells per Braille character.
+    Braille,
+    /// Blocks of cells per shade character, darker the more of them are alive.
+    Shaded,
+}
+
+impl RenderMode {
+    /// Returns the next denser mode, showing more cells per character.
+    pub fn zoom_out(self) -> Self {
+        match self {
+            RenderMode::Block => RenderMode::HalfBlock,
+            RenderMode::HalfBlock => RenderMode::Braille,
+            RenderMode::Braille | RenderMode::Shaded => RenderMode::Shaded,
+        }
+    }
+
+    /// Returns the next sparser mode, showing fewer cells per character.
+    pub fn zoom_in(self) -> Self {
+        match self {
+            RenderMode::Block | RenderMode::HalfBlock => RenderMode::Block,
+            RenderMode::Braille => RenderMode::HalfBlock,
+            RenderMode::Shaded => RenderMode::Braille,
+        }
+    }
+
+    /// Short name shown in the status bar.
+    fn name(self) -> &'static str {
+        match self {
+            RenderMode::Block => "block",
+            RenderMode::HalfBlock => "half",
+            RenderMode::Braille => "braille",
+            RenderMode::Shaded => "shaded",
+        }
+    }
+
+    /// Returns the renderer drawing this mode, with `cell_width` characters per cell in
+    /// [`RenderMode::Block`].
+    fn renderer(self, cell_width: usize) -> Box<dyn Renderer> {
+        match self {
+            RenderMode::Block => Box::new(BlockRenderer { cell_width }),
+            RenderMode::HalfBlock => Box::new(HalfBlockRenderer),
+            RenderMode::Braille => Box::new(BrailleRenderer),
+            RenderMode::Shaded => Box::new(ShadedRenderer),
+        }
+    }
+
+    /// Converts a character (x, y) offset from the grid's origin into the (row, col) of the
+    /// top-left cell drawn by that character.
+    pub fn cell_at(self, x: usize, y: usize, cell_width: usize) -> (usize, usize) {
+        self.renderer(cell_width).cell_at(x, y)
+    }
+}
 
 impl Widget for &App {
     fn render(self, area: Rect, buf: &mut Buffer) {
@@ -30,39 +113,268 @@ impl Widget for &App {
             ]);
         let chunks = layout.split(area);
 
-        GridDisplay::new(&self.game.grid).render(chunks[0], buf);
+        let trail_length = self.show_trails.then_some(self.settings.trail_length);
+        GridDisplay::new(&self.game.grid)
+            .ruler(self.show_ruler)
+            .trails(trail_length)
+            .mode(self.render_mode)
+            .cell_width(self.cell_width)
+            .drawn_area(&self.grid_area)
+            .title(self.title.as_deref())
+            .period(self.game.period())
+            .stabilized_at(self.game.stabilized_at())
+            .selection(self.active_selection())
+            .cursor(self.select_mode.then_some(self.select_cursor))
+            .live_color(self.settings.live_color)
+            .background(self.settings.background)
+            .sparkle(self.show_sparkle.then_some(self.settings.sparkle_color))
+            .render(chunks[0], buf);
+
+        // Overlay the stats panel on the bottom of the grid area
+        if self.show_stats {
+            let [_, stats_area] =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(chunks[0]);
+            Clear.render(stats_area, buf);
+            StatsPanel::new(&self.game).render(stats_area, buf);
+        }
 
-        StatusBar::new(&self.game).render(chunks[1], buf);
+        match (&self.jump, &self.replay, &self.command_input) {
+            (Some(jump), _, _) => JumpProgress::new(jump).render(chunks[1], buf),
+            (None, Some(replay), _) => {
+                ReplayProgress::new(replay, &self.game).render(chunks[1], buf)
+            }
+            (None, None, Some(input)) => CommandLine::new(input).render(chunks[1], buf),
+            (None, None, None) => StatusBar::new(&self.game)
+                .alert(self.population_alert())
+                .mode(self.render_mode)
+                .turbo(self.turbo)
+                .max_rate(self.rate_limited.then_some(self.settings.max_rate))
+                .brush_radius(self.brush_radius)
+                .wall_mode(self.wall_mode)
+                .editing(self.editing)
+                .size_locked(self.size_locked)
+                .selection(self.active_selection())
+                .recording(
+                    self.recording
+                        .as_ref()
+                        .filter(|_| self.is_recording)
+                        .map(|recording| recording.len()),
+                )
+                .fields(&self.settings.status_fields)
+                .hints(key_hints())
+                .message(self.status_message.as_deref())
+                .render(chunks[1], buf),
+        }
+
+        if self.show_help {
+            HelpPanel::new(key_bindings()).render(area, buf);
+        }
     }
 }
 
 /// Widget for rendering the game grid.
 struct GridDisplay<'a> {
     grid: &'a Grid,
+    show_ruler: bool,
+    /// Colors and trails to draw with.
+    ///
+    /// Trails and sparkles, like walls, are only drawn in [`RenderMode::Block`].
+    theme: Theme,
+    mode: RenderMode,
+    /// Characters per cell in [`RenderMode::Block`].
+    cell_width: usize,
+    /// Where to record the area the grid was drawn in, if anywhere.
+    drawn_area: Option<&'a Cell<Option<Rect>>>,
+    /// Panel title, or `None` for the default.
+    title: Option<&'a str>,
+    /// Detected period to show after the title, if any.
+    period: Option<u64>,
+    /// Generation the detected period started at, if any.
+    stabilized_at: Option<u64>,
+    /// Rectangle to highlight as (top, left, height, width), if any.
+    selection: Option<(usize, usize, usize, usize)>,
+    /// Cell to mark as the select cursor, if any.
+    cursor: Option<(usize, usize)>,
 }
 
 impl<'a> GridDisplay<'a> {
     fn new(grid: &'a Grid) -> Self {
-        Self { grid }
+        Self {
+            grid,
+            show_ruler: false,
+            theme: Theme::default(),
+            mode: RenderMode::default(),
+            cell_width: DEFAULT_CELL_WIDTH,
+            drawn_area: None,
+            title: None,
+            period: None,
+            stabilized_at: None,
+            selection: None,
+            cursor: None,
+        }
+    }
+
+    /// Sets whether to draw row/column rulers.
+    fn ruler(mut self, show_ruler: bool) -> Self {
+        self.show_ruler = show_ruler;
+        self
+    }
+
+    /// Sets how many generations dead cells stay visible, or `None` to disable trails.
+    fn trails(mut self, trail_length: Option<u16>) -> Self {
+        self.theme.trail_length = trail_length;
+        self
+    }
+
+    /// Sets how cells are packed into characters.
+    fn mode(mut self, mode: RenderMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the number of characters per cell in [`RenderMode::Block`].
+    fn cell_width(mut self, cell_width: usize) -> Self {
+        self.cell_width = cell_width;
+        self
+    }
+
+    /// Sets where to record the area the grid is drawn in, or `None` if it isn't drawn.
+    fn drawn_area(mut self, drawn_area: &'a Cell<Option<Rect>>) -> Self {
+        self.drawn_area = Some(drawn_area);
+        self
+    }
+
+    /// Sets the panel title, or `None` for the default.
+    fn title(mut self, title: Option<&'a str>) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Sets the detected period to show after the title.
+    fn period(mut self, period: Option<u64>) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Sets the generation the detected period started at.
+    fn stabilized_at(mut self, generation: Option<u64>) -> Self {
+        self.stabilized_at = generation;
+        self
+    }
+
+    /// Sets the rectangle to highlight as (top, left, height, width).
+    fn selection(mut self, selection: Option<(usize, usize, usize, usize)>) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the cell to mark as the select cursor.
+    fn cursor(mut self, cursor: Option<(usize, usize)>) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Sets the color cells that just changed flash in, or `None` to draw them normally.
+    fn sparkle(mut self, color: Option<Color>) -> Self {
+        self.theme.sparkle = color;
+        self
+    }
+
+    /// Sets the color of live cells.
+    fn live_color(mut self, color: Color) -> Self {
+        self.theme.live = color;
+        self
+    }
+
+    /// Sets the color behind the cells, or [`Color::Reset`] for the terminal's background.
+    fn background(mut self, color: Color) -> Self {
+        self.theme.background = color;
+        self
+    }
+
+    /// Highlights the selection and select cursor over the cells already drawn at `grid_area`.
+    fn render_selection(&self, renderer: &dyn Renderer, grid_area: Rect, buf: &mut Buffer) {
+        if let Some((top, left, height, width)) = self.selection {
+            let (first, last) = ((top, left), (top + height - 1, left + width - 1));
+            let area = char_area(renderer, grid_area, first, last);
+            buf.set_style(area, Style::default().bg(Color::DarkGray));
+        }
+        if let Some(cell) = self.cursor {
+            let area = char_area(renderer, grid_area, cell, cell);
+            buf.set_style(area, Style::default().add_modifier(Modifier::REVERSED));
+        }
+    }
+
+    /// Renders row and column labels every `RULER_SPACING` cells around `grid_area`.
+    fn render_ruler(&self, renderer: &dyn Renderer, grid_area: Rect, buf: &mut Buffer) {
+        let style = Style::default().fg(Color::DarkGray);
+
+        // Column labels sit on the row above the grid
+        let top = grid_area.y - 1;
+        for col in (0..self.grid.width()).step_by(RULER_SPACING) {
+            let x = grid_area.x + renderer.char_offset(0, col).0 as u16;
+            let max_width = (grid_area.right() - x) as usize;
+            buf.set_stringn(x, top, format!("╷{col}"), max_width, style);
+        }
+
+        // Row labels are right-aligned against the grid's left edge
+        let label_width = ruler_label_width(self.grid.height());
+        let left = grid_area.x - label_width;
+        for row in (0..self.grid.height()).step_by(RULER_SPACING) {
+            let y = grid_area.y + renderer.char_offset(row, 0).1 as u16;
+            let label = format!("{row:>width$}╶", width = label_width as usize - 1);
+            buf.set_string(left, y, label, style);
+        }
     }
 }
 
 impl<'a> Widget for GridDisplay<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let block = Block::default().borders(Borders::ALL).title("Game of Life");
+        let title = self.title.unwrap_or(DEFAULT_TITLE);
+        let stabilized = self
+            .stabilized_at
+            .map(|generation| format!(", stabilized at gen {generation}"))
+            .unwrap_or_default();
+        let title = match self.period {
+            Some(1) => format!("{title} (still{stabilized})"),
+            Some(period) => format!("{title} (period {period}{stabilized})"),
+            None => title.to_string(),
+        };
+        let block = Block::default().borders(Borders::ALL).title(title);
         let inner = block.inner(area);
         block.render(area, buf);
+        if let Some(drawn_area) = self.drawn_area {
+            drawn_area.set(None);
+        }
+
+        // Nothing fits when the terminal is shrunk too far
+        if inner.is_empty() {
+            return;
+        }
+
+        // Reserve space for the ruler labels
+        let (label_width, label_height) = if self.show_ruler {
+            (ruler_label_width(self.grid.height()), 1)
+        } else {
+            (0, 0)
+        };
+        let available = Rect {
+            x: inner.x.saturating_add(label_width),
+            y: inner.y.saturating_add(label_height),
+            width: inner.width.saturating_sub(label_width),
+            height: inner.height.saturating_sub(label_height),
+        };
 
         // Calculate display capacity
-        let max_cols = (inner.width as usize) * CELL_WIDTH;
-        let max_rows = inner.height as usize;
+        let renderer = self.mode.renderer(self.cell_width);
+        let (max_cols, max_rows) =
+            renderer.capacity(available.width as usize, available.height as usize);
 
         // Display warning if grid exceeds capacity of display area
-        if self.grid.width > max_cols || self.grid.height > max_rows {
-            let warning = format!(
-                "Grid {}×{} exceeds display capacity {}×{}",
-                self.grid.width, self.grid.height, max_cols, max_rows
-            );
+        let (width, height) = self.grid.dimensions();
+        if width > max_cols || height > max_rows {
+            let warning =
+                format!("Grid {width}×{height} exceeds display capacity {max_cols}×{max_rows}");
 
             Paragraph::new(warning)
                 .style(Style::default().fg(Color::Yellow))
@@ -71,44 +383,294 @@ impl<'a> Widget for GridDisplay<'a> {
             return;
         }
 
-        // Render the grid using the pre-formatted string representation
-        Paragraph::new(self.grid.to_string())
-            .style(Style::default().fg(Color::White))
-            .alignment(Alignment::Center)
-            .render(inner, buf);
+        // Center the grid horizontally within the available area
+        let (display_width, display_height) = renderer.display_size(width, height);
+        let grid_area = Rect {
+            x: available.x + (available.width - display_width as u16) / 2,
+            y: available.y,
+            width: display_width as u16,
+            height: display_height as u16,
+        };
+
+        if let Some(drawn_area) = self.drawn_area {
+            drawn_area.set(Some(grid_area));
+        }
+
+        if self.show_ruler {
+            self.render_ruler(renderer.as_ref(), grid_area, buf);
+        }
+
+        renderer.render(self.grid, &self.theme, grid_area, buf);
+        self.render_selection(renderer.as_ref(), grid_area, buf);
     }
 }
 
 /// Widget for the status bar
 struct StatusBar<'a> {
     game: &'a Game,
+    /// Population warning to flash, if any.
+    alert: Option<&'a str>,
+    mode: RenderMode,
+    /// Whether turbo mode is on.
+    turbo: bool,
+    /// Generations per second the simulation is limited to, if any.
+    max_rate: Option<u32>,
+    /// Radius of the drawing brush in cells.
+    brush_radius: usize,
+    /// Whether the brush paints walls instead of cells.
+    wall_mode: bool,
+    /// Whether the simulation was paused by an edit.
+    editing: bool,
+    /// Whether the grid size is locked.
+    size_locked: bool,
+    /// Selected rectangle as (top, left, height, width), if any.
+    selection: Option<(usize, usize, usize, usize)>,
+    /// Generations recorded so far, if recording.
+    recording: Option<u64>,
+    /// Fields to show, in order.
+    fields: &'a [StatusField],
+    /// Key hints shown when there is no message, as room allows.
+    hints: &'a [KeyHint],
+    /// Feedback message shown in place of the help text, if any.
+    message: Option<&'a str>,
 }
 
 impl<'a> StatusBar<'a> {
     fn new(game: &'a Game) -> Self {
-        Self { game }
+        Self {
+            game,
+            alert: None,
+            mode: RenderMode::default(),
+            turbo: false,
+            max_rate: None,
+            brush_radius: 0,
+            wall_mode: false,
+            editing: false,
+            size_locked: false,
+            selection: None,
+            recording: None,
+            fields: StatusField::DEFAULT,
+            hints: &[],
+            message: None,
+        }
+    }
+
+    /// Sets the population warning to flash.
+    fn alert(mut self, alert: Option<&'a str>) -> Self {
+        self.alert = alert;
+        self
+    }
+
+    /// Sets the rendering mode to display.
+    fn mode(mut self, mode: RenderMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether to show turbo mode in place of the tick interval.
+    fn turbo(mut self, turbo: bool) -> Self {
+        self.turbo = turbo;
+        self
+    }
+
+    /// Sets the generation rate limit to display, or `None` if unlimited.
+    fn max_rate(mut self, max_rate: Option<u32>) -> Self {
+        self.max_rate = max_rate;
+        self
+    }
+
+    /// Sets the drawing brush radius to display.
+    fn brush_radius(mut self, brush_radius: usize) -> Self {
+        self.brush_radius = brush_radius;
+        self
+    }
+
+    /// Sets whether to show that the brush paints walls.
+    fn wall_mode(mut self, wall_mode: bool) -> Self {
+        self.wall_mode = wall_mode;
+        self
+    }
+
+    /// Sets whether to show that the simulation was paused by an edit.
+    fn editing(mut self, editing: bool) -> Self {
+        self.editing = editing;
+        self
+    }
+
+    /// Sets whether to show that the grid size is locked.
+    fn size_locked(mut self, size_locked: bool) -> Self {
+        self.size_locked = size_locked;
+        self
+    }
+
+    /// Sets the selected rectangle to display as (top, left, height, width).
+    fn selection(mut self, selection: Option<(usize, usize, usize, usize)>) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the number of generations recorded so far, or `None` if not recording.
+    fn recording(mut self, recording: Option<u64>) -> Self {
+        self.recording = recording;
+        self
+    }
+
+    /// Sets the fields to show, in order.
+    fn fields(mut self, fields: &'a [StatusField]) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Sets the key hints to show when there is no message.
+    fn hints(mut self, hints: &'a [KeyHint]) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    /// Sets a feedback message to show in place of the help text.
+    fn message(mut self, message: Option<&'a str>) -> Self {
+        self.message = message;
+        self
+    }
+}
+
+impl StatusBar<'_> {
+    /// Formats a field, or returns `None` if it has nothing to show right now.
+    fn field_text(&self, field: StatusField, growth: isize) -> Option<String> {
+        let game = self.game;
+        let text = match field {
+            StatusField::State => match game.state {
+                GameState::Paused if self.editing => "EDITING (paused)".to_string(),
+                GameState::Paused => "PAUSED".to_string(),
+                GameState::Running => "RUNNING".to_string(),
+            },
+            StatusField::Generation => format!("gen: {}", game.generation),
+            StatusField::Time => format_running_time(game.running_time()),
+            StatusField::Rate => format!("{:.1} gen/s", game.average_rate()?),
+            StatusField::Population => match growth {
+                0 => format!("pop: {}", game.grid.live_count()),
+                growth => format!("pop: {} ({growth:+})", game.grid.live_count()),
+            },
+            StatusField::Oldest => format!("oldest: {}", game.grid.max_age()?),
+            StatusField::Rule if game.is_reversible() => format!("{} reversible", game.rule),
+            StatusField::Rule => game.rule.to_string(),
+            StatusField::Size if self.size_locked => {
+                format!("{}×{} locked", game.grid.width(), game.grid.height())
+            }
+            StatusField::Size => format!("{}×{}", game.grid.width(), game.grid.height()),
+            StatusField::Speed if self.turbo => "TURBO".to_string(),
+            StatusField::Speed => {
+                let millis = game.tick_interval.as_millis();
+                match game.speed_preset() {
+                    Some(preset) => format!("{millis}ms ({preset})"),
+                    None => format!("{millis}ms"),
+                }
+            }
+            StatusField::Limit => format!("≤{} gen/s", self.max_rate?),
+            StatusField::Mode => self.mode.name().to_string(),
+            StatusField::Brush if self.wall_mode => format!("brush: {} walls", self.brush_radius),
+            StatusField::Brush => format!("brush: {}", self.brush_radius),
+            StatusField::Selection => {
+                let (top, left, height, width) = self.selection?;
+                format!("sel: {width}×{height} at ({top},{left})")
+            }
+            StatusField::Recording => format!("● rec {}", self.recording?),
+            StatusField::Target => match (game.run_target, game.pause_after) {
+                (Some(target), _) => format!("→ gen {target}"),
+                (None, Some(left)) => format!("→ {left} left"),
+                (None, None) => return None,
+            },
+            StatusField::Pattern => patterns::identify(&game.grid)?.to_string(),
+            StatusField::Spaceship => {
+                let (period, displacement) = game.detect_spaceship()?;
+                format_spaceship(period, displacement)
+            }
+            StatusField::Period => {
+                let period = game.period()?;
+                let name = (game.rule == Rule::CONWAY)
+                    .then(|| patterns::identify_oscillator(&game.grid, period))
+                    .flatten();
+                match name {
+                    Some(name) => format!("period: {period} ({name})"),
+                    None => format!("period: {period}"),
+                }
+            }
+            StatusField::Density => {
+                let total_cells = game.grid.width() * game.grid.height();
+                let density = game.grid.live_count() as f64 / total_cells as f64;
+                format!("density: {:.1}%", density * 100.0)
+            }
+            StatusField::Activity => format!("activity: {}", game.last_step.activity()),
+            StatusField::Clusters => format!("clusters: {}", game.grid.count_clusters(true)),
+            StatusField::Fingerprint => format!("fp: {}", game.grid.fingerprint()),
+        };
+        Some(text)
     }
 }
 
 impl<'a> Widget for StatusBar<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let (state_text, state_color) = match self.game.state {
-            GameState::Paused => ("PAUSED", Color::Red),
-            GameState::Running => ("RUNNING", Color::Green),
-        };
+        if area.is_empty() {
+            return;
+        }
 
-        let status_parts = [
-            state_text.to_string(),
-            format!("gen: {}", self.game.generation),
-            format!("pop: {}", self.game.grid.population),
-            format!("{}×{}", self.game.grid.width, self.game.grid.height),
-            format!("{}ms", self.game.tick_interval.as_millis()),
-        ];
+        let state_color = match self.game.state {
+            GameState::Paused => Color::Red,
+            GameState::Running => Color::Green,
+        };
+        // Color the population by the last step's net growth, unless there's no step to show
+        let growth = match self.game.state {
+            GameState::Running => self.game.last_step.net_growth(),
+            GameState::Paused => 0,
+        };
+        // Stand apart from the bar's own color, which follows the running state
+        let population_style = match growth {
+            0 => Style::default().fg(Color::Reset),
+            1.. => Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+            _ => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        };
 
-        let status_text = status_parts.join(" │ ");
-        let help_text = " -- <space>: pause │ <r>: random │ <↑/↓>: speed │ <q>: quit";
+        let mut spans = Vec::new();
+        for &field in self.fields {
+            let Some(text) = self.field_text(field, growth) else {
+                continue;
+            };
+            if !spans.is_empty() {
+                spans.push(" │ ".into());
+            }
+            spans.push(if field == StatusField::Population {
+                Span::styled(text, population_style)
+            } else {
+                text.into()
+            });
+        }
+        if let Some(alert) = self.alert {
+            // Flash by inverting colors on alternate generations
+            let mut style = Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD);
+            if self.game.generation.is_multiple_of(2) {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            if !spans.is_empty() {
+                spans.push(" │ ".into());
+            }
+            spans.push(Span::styled(format!("⚠ {alert}"), style));
+        }
+        match self.message {
+            Some(message) => spans.push(format!(" -- {message}").into()),
+            None => {
+                // Fit as many hints as the rest of the bar has room for
+                let used = Line::from(spans.clone()).width();
+                let inner_width = area.width.saturating_sub(2) as usize;
+                let help_text = help_text(self.hints, inner_width.saturating_sub(used));
+                spans.push(help_text.into());
+            }
+        }
 
-        let content = Line::from(vec![status_text.into(), help_text.into()]);
+        let content = Line::from(spans);
 
         Paragraph::new(content)
             .style(Style::default().fg(state_color))
@@ -118,14 +680,310 @@ impl<'a> Widget for StatusBar<'a> {
     }
 }
 
+/// Widget for the statistics overlay.
+struct StatsPanel<'a> {
+    game: &'a Game,
+}
+
+impl<'a> StatsPanel<'a> {
+    fn new(game: &'a Game) -> Self {
+        Self { game }
+    }
+}
+
+impl<'a> Widget for StatsPanel<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+
+        // Neighbor counts of live cells as a row of bars scaled to the largest count
+        let histogram = self.game.grid.neighbor_histogram();
+        let max_count = histogram.iter().copied().max().unwrap_or(0).max(1);
+        let bars: Vec<String> = histogram
+            .iter()
+            .enumerate()
+            .map(|(neighbors, &count)| {
+                let bar = BARS[count * (BARS.len() - 1) / max_count];
+                format!("{neighbors}:{bar}{count}")
+            })
+            .collect();
+
+        let title = format!(
+            "Neighbors ({} clusters)",
+            self.game.grid.count_clusters(true)
+        );
+        Paragraph::new(bars.join(" "))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .render(area, buf);
+    }
+}
+
+/// Overlay listing key bindings in a centered panel, dimming everything behind it.
+struct HelpPanel<'a> {
+    bindings: &'a [KeyHint],
+}
+
+impl<'a> HelpPanel<'a> {
+    fn new(bindings: &'a [KeyHint]) -> Self {
+        Self { bindings }
+    }
+}
+
+impl<'a> Widget for HelpPanel<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, Style::default().add_modifier(Modifier::DIM));
+
+        // Split the bindings into as many columns as it takes to fit them vertically
+        let rows = (area.height.saturating_sub(4) as usize).max(1);
+        let key_width = self.bindings.iter().map(|b| b.key.len()).max().unwrap_or(0);
+        let entries: Vec<String> = self
+            .bindings
+            .iter()
+            .map(|binding| format!("{:>key_width$}  {}", binding.key, binding.action))
+            .collect();
+        let entry_width = entries.iter().map(|e| e.chars().count()).max().unwrap_or(0);
+        let columns: Vec<&[String]> = entries.chunks(rows).collect();
+        let lines: Vec<Line> = (0..rows.min(entries.len()))
+            .map(|row| {
+                let cells: Vec<String> = columns
+                    .iter()
+                    .filter_map(|column| column.get(row))
+                    .map(|entry| format!("{entry:<entry_width$}"))
+                    .collect();
+                Line::from(cells.join("   "))
+            })
+            .collect();
+
+        let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 4;
+        let height = lines.len() as u16 + 2;
+        let panel = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width: width.min(area.width),
+            height: height.min(area.height),
+        };
+        Clear.render(panel, buf);
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Keys (any key closes)"),
+            )
+            .style(Style::default().remove_modifier(Modifier::DIM))
+            .render(panel, buf);
+    }
+}
+
+/// Widget for the `:` command prompt, drawn in place of the status bar while typing.
+struct CommandLine<'a> {
+    input: &'a str,
+}
+
+impl<'a> CommandLine<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input }
+    }
+}
+
+impl<'a> Widget for CommandLine<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+
+        let content = Line::from(vec![":".into(), self.input.into(), "█".into()]);
+
+        Paragraph::new(content)
+            .block(Block::default().borders(Borders::ALL).title("Command"))
+            .render(area, buf);
+    }
+}
+
+/// Progress bar for a background jump, shown in place of the status bar.
+struct JumpProgress<'a> {
+    jump: &'a Jump,
+}
+
+impl<'a> JumpProgress<'a> {
+    fn new(jump: &'a Jump) -> Self {
+        Self { jump }
+    }
+}
+
+impl<'a> Widget for JumpProgress<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+
+        let Jump { done, total, .. } = *self.jump;
+        let ratio = if total == 0 {
+            1.0
+        } else {
+            done as f64 / total as f64
+        };
+        let label = format!("{done}/{total} generations -- <Esc>: cancel");
+
+        Gauge::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(match self.jump.until {
+                        Some(condition) => format!("Until {condition}"),
+                        None => format!("Goto {}", self.jump.target),
+                    }),
+            )
+            .gauge_style(Style::default().fg(Color::Green))
+            .ratio(ratio.clamp(0.0, 1.0))
+            .label(label)
+            .render(area, buf);
+    }
+}
+
+/// Scrubber for a replay, shown in place of the status bar.
+struct ReplayProgress<'a> {
+    replay: &'a Replay,
+    game: &'a Game,
+}
+
+impl<'a> ReplayProgress<'a> {
+    fn new(replay: &'a Replay, game: &'a Game) -> Self {
+        Self { replay, game }
+    }
+}
+
+impl<'a> Widget for ReplayProgress<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+
+        let (position, length) = (self.replay.position, self.replay.recording.len());
+        let ratio = if length == 0 {
+            1.0
+        } else {
+            position as f64 / length as f64
+        };
+        let label = format!(
+            "gen {} ({position}/{length}) -- <←/→>: seek │ <space>: pause │ <Esc>: exit",
+            self.game.generation
+        );
+        let title = if self.game.is_paused() {
+            "Replay (paused)"
+        } else {
+            "Replay"
+        };
+
+        Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .gauge_style(Style::default().fg(Color::Cyan))
+            .ratio(ratio.clamp(0.0, 1.0))
+            .label(label)
+            .render(area, buf);
+    }
+}
+
+/// Returns the characters drawing the cells from `first` to `last`, given as (row, col),
+/// within `grid_area`.
+fn char_area(
+    renderer: &dyn Renderer,
+    grid_area: Rect,
+    first: (usize, usize),
+    last: (usize, usize),
+) -> Rect {
+    let (x, y) = renderer.char_offset(first.0, first.1);
+    let (last_x, last_y) = renderer.char_offset(last.0, last.1);
+    Rect {
+        x: grid_area.x.saturating_add(x as u16),
+        y: grid_area.y.saturating_add(y as u16),
+        width: (last_x - x + renderer.cell_chars()) as u16,
+        height: (last_y - y + 1) as u16,
+    }
+    .intersection(grid_area)
+}
+
+/// Builds the ` -- <key>: action │ ...` help text from as many leading hints as fit within
+/// `max_width` characters, or an empty string if none fit.
+fn help_text(hints: &[KeyHint], max_width: usize) -> String {
+    let mut text = String::new();
+    for (i, hint) in hints.iter().enumerate() {
+        let separator = if i == 0 { " -- " } else { " │ " };
+        let entry = format!("{separator}<{}>: {}", hint.key, hint.action);
+        if text.chars().count() + entry.chars().count() > max_width {
+            break;
+        }
+        text.push_str(&entry);
+    }
+    text
+}
+
+/// Formats a running time as `m:ss`, or `h:mm:ss` from an hour up.
+fn format_running_time(running_time: Duration) -> String {
+    let seconds = running_time.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+/// Formats a spaceship's speed and heading, e.g. `c/4 diagonal ↘` for a glider.
+///
+/// Speed is in the usual units of `c`, one cell per generation along the axis moved furthest,
+/// reduced to lowest terms.
+fn format_spaceship(period: u32, (rows, cols): (i32, i32)) -> String {
+    fn gcd(a: u32, b: u32) -> u32 {
+        if b == 0 { a } else { gcd(b, a % b) }
+    }
+
+    let distance = rows.unsigned_abs().max(cols.unsigned_abs());
+    let divisor = gcd(distance, period);
+    let speed = match (distance / divisor, period / divisor) {
+        (1, 1) => "c".to_string(),
+        (1, period) => format!("c/{period}"),
+        (distance, 1) => format!("{distance}c"),
+        (distance, period) => format!("{distance}c/{period}"),
+    };
+    let heading = if rows == 0 || cols == 0 {
+        "orthogonal"
+    } else if rows.abs() == cols.abs() {
+        "diagonal"
+    } else {
+        "oblique"
+    };
+    let arrow = match (rows.signum(), cols.signum()) {
+        (-1, -1) => '↖',
+        (-1, 0) => '↑',
+        (-1, _) => '↗',
+        (0, -1) => '←',
+        (0, _) => '→',
+        (_, -1) => '↙',
+        (_, 0) => '↓',
+        _ => '↘',
+    };
+    format!("{speed} {heading} {arrow}")
+}
+
+/// Width of the row label column for a grid with `height` rows, including the tick mark.
+fn ruler_label_width(height: usize) -> u16 {
+    let last_label = height.saturating_sub(1) / RULER_SPACING * RULER_SPACING;
+    last_label.to_string().len() as u16 + 1
+}
+
 /// Calculates appropriate grid dimensions based on terminal size.
 /// Accounts for borders, margins, and the status bar.
 /// Clamps the result within bounds to ensure usablity.
-pub fn calculate_grid_size(terminal_size: (u16, u16)) -> (usize, usize) {
+pub fn calculate_grid_size(terminal_size: (u16, u16), cell_width: usize) -> (usize, usize) {
     let (term_width, term_height) = terminal_size;
 
     // 2 border + 2 margin
-    let available_width = term_width.saturating_sub(4) as usize / CELL_WIDTH;
+    let available_width = term_width.saturating_sub(4) as usize / cell_width;
     // 2 border + 2 margin + 3 status box
     let available_height = term_height.saturating_sub(7) as usize;
 
@@ -134,3 +992,83 @@ pub fn calculate_grid_size(terminal_size: (u16, u16)) -> (usize, usize) {
 
     (grid_width, grid_height)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::CellState;
+
+    const MODES: [RenderMode; 4] = [
+        RenderMode::Block,
+        RenderMode::HalfBlock,
+        RenderMode::Braille,
+        RenderMode::Shaded,
+    ];
+
+    #[test]
+    fn grid_renders_into_tiny_areas() {
+        let mut grid = Grid::new(3, 3);
+        grid.stamp(&[(0, 0), (1, 1), (2, 2)], 0, 0);
+        for mode in MODES {
+            for ruler in [false, true] {
+                for (width, height) in [(0, 0), (1, 1), (0, 5), (5, 0), (2, 2), (3, 3), (4, 4)] {
+                    for (x, y) in [(0, 0), (7, 3)] {
+                        let area = Rect::new(x, y, width, height);
+                        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+                        let drawn = Cell::new(Some(Rect::default()));
+                        GridDisplay::new(&grid)
+                            .mode(mode)
+                            .ruler(ruler)
+                            .drawn_area(&drawn)
+                            .render(area, &mut buf);
+                        if let Some(grid_area) = drawn.get() {
+                            assert_eq!(area.intersection(grid_area), grid_area);
+                        }
+                        // Nothing fits inside the border
+                        if width <= 2 || height <= 2 {
+                            assert_eq!(drawn.get(), None, "{mode:?} in {area:?}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ruler_labels_stay_inside_the_area() {
+        let mut grid = Grid::new(12, 12);
+        grid.set(0, 0, CellState::Alive);
+        for mode in MODES {
+            // The smallest area at the top-left corner of the screen that fits the grid
+            let (width, height) = (1..60)
+                .flat_map(|height| (1..60).map(move |width| (width, height)))
+                .find(|&(width, height)| {
+                    let area = Rect::new(0, 0, width, height);
+                    let drawn = Cell::new(None);
+                    GridDisplay::new(&grid)
+                        .mode(mode)
+                        .ruler(true)
+                        .drawn_area(&drawn)
+                        .render(area, &mut Buffer::empty(area));
+                    drawn.get().is_some()
+                })
+                .unwrap();
+            let area = Rect::new(0, 0, width, height);
+            let mut buf = Buffer::empty(area);
+            let drawn = Cell::new(None);
+            GridDisplay::new(&grid)
+                .mode(mode)
+                .ruler(true)
+                .drawn_area(&drawn)
+                .render(area, &mut buf);
+
+            let grid_area = drawn.get().unwrap();
+            let label_width = ruler_label_width(grid.height());
+            assert!(grid_area.x > label_width, "{mode:?}: {grid_area:?}");
+            assert!(grid_area.y >= 2, "{mode:?}: {grid_area:?}");
+            assert_eq!(buf[(grid_area.x, grid_area.y - 1)].symbol(), "╷");
+            assert_eq!(buf[(grid_area.x - 1, grid_area.y)].symbol(), "╶");
+            assert_eq!(buf[(grid_area.x - 2, grid_area.y)].symbol(), "0");
+        }
+    }
+}
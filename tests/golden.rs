@@ -0,0 +1,72 @@
+//! Golden tests for [`Game::step`]: known patterns stepped a fixed number of generations must
+//! match the boards in `tests/golden` cell for cell.
+//!
+//! Each fixture is a whole board in plaintext, dead cells included, so its size is the size of
+//! the board. The expected boards were worked out independently of ratgol, and every way of
+//! stepping the board has to reproduce them.
+
+use ratgol::{game::Game, grid::Grid, patterns};
+
+/// Builds a game on the board a fixture draws.
+fn load(fixture: &str) -> Game {
+    let rows: Vec<&str> = fixture
+        .lines()
+        .filter(|line| !line.starts_with('!'))
+        .collect();
+    let width = rows.iter().map(|row| row.len()).max().unwrap();
+    let mut game = Game::new((width, rows.len()));
+    let cells = patterns::parse_plaintext(fixture).unwrap();
+    game.grid.stamp(&cells, 0, 0);
+    game
+}
+
+/// Returns the board a fixture draws.
+fn board(fixture: &str) -> Grid {
+    load(fixture).grid
+}
+
+/// Steps the board in `start` for `generations` and checks it against the board in `expected`.
+fn assert_golden(start: &str, generations: u64, expected: &str) {
+    let mut game = load(start);
+    for _ in 0..generations {
+        game.step();
+    }
+    let expected = board(expected);
+    assert!(
+        game.grid == expected,
+        "after {generations} generations expected\n{}\ngot\n{}",
+        expected.render(),
+        game.grid.render()
+    );
+    assert_eq!(game.generation, generations);
+}
+
+const BLINKER: &str = include_str!("golden/blinker.cells");
+const BLINKER_1: &str = include_str!("golden/blinker.1.cells");
+const GLIDER: &str = include_str!("golden/glider.cells");
+const GLIDER_4: &str = include_str!("golden/glider.4.cells");
+const PULSAR: &str = include_str!("golden/pulsar.cells");
+const PULSAR_1: &str = include_str!("golden/pulsar.1.cells");
+const PULSAR_2: &str = include_str!("golden/pulsar.2.cells");
+
+#[test]
+fn blinker_flips_and_has_period_2() {
+    assert_golden(BLINKER, 1, BLINKER_1);
+    assert_golden(BLINKER, 2, BLINKER);
+    assert_golden(BLINKER, 100, BLINKER);
+}
+
+#[test]
+fn glider_moves_one_cell_diagonally_every_4_generations() {
+    assert_golden(GLIDER, 4, GLIDER_4);
+    // Around the 8×8 torus and back to where it started
+    assert_golden(GLIDER, 32, GLIDER);
+}
+
+#[test]
+fn pulsar_runs_through_its_phases_with_period_3() {
+    assert_golden(PULSAR, 1, PULSAR_1);
+    assert_golden(PULSAR, 2, PULSAR_2);
+    assert_golden(PULSAR, 3, PULSAR);
+    assert_golden(PULSAR, 300, PULSAR);
+}